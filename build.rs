@@ -0,0 +1,42 @@
+//! Generates a test per example listed in `./example/YYYY/manifest`, run by
+//! `aoc::examples`.
+
+use std::{env, fs, path::PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=example");
+
+    let mut years: Vec<(usize, PathBuf)> = fs::read_dir("example")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| Some((entry.file_name().to_str()?.parse().ok()?, entry.path())))
+        .collect();
+    years.sort();
+
+    let mut tests = String::new();
+    for (year, dir) in years {
+        let Ok(manifest) = fs::read_to_string(dir.join("manifest")) else {
+            continue;
+        };
+
+        for line in manifest.lines() {
+            let Some(name) = line
+                .trim()
+                .strip_prefix('[')
+                .and_then(|l| l.strip_suffix(']'))
+            else {
+                continue;
+            };
+            let name = name.trim();
+            let test = name.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+            tests.push_str(&format!(
+                "#[test]\nfn example_{year}_{test}() {{\n    check({year}, {name:?});\n}}\n\n"
+            ));
+        }
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -1,49 +1,1094 @@
+//! The [`Solution`] trait, the registry of days and the runner.
+
+use crate::{
+    error::{AocError, Result},
+    utils::hash,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
 use std::time::Duration;
 use std::time::Instant;
 
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+mod answer;
+mod answers;
+mod baseline;
+mod bench;
+mod crosscheck;
+mod examples;
+mod history;
+mod input;
+mod memory;
+/// Machine-readable output of reports and benches.
+pub mod output;
+mod submit;
+
+pub use answer::Answer;
+pub use answers::{Answers, Status};
+pub use baseline::{commit, Baseline, Baselines, Comparison, DEFAULT_THRESHOLD};
+pub use bench::{bench_day, Bench, BenchOptions, Stats};
+pub use crosscheck::{crosscheck, Check, Crosscheck};
+pub use examples::Example;
+pub use history::{Attempt, History, Verdict};
+pub use input::Input;
+pub use memory::{Counting, Memory};
+pub use output::Format;
+pub use submit::submit;
+
+/// A registered day: its year, number, puzzle title and its solution.
+pub struct Day {
+    /// Year of the event.
+    pub year: usize,
+    /// Day of the month, 1 to 25.
+    pub number: usize,
+    /// Puzzle title, as shown on the website.
+    pub title: &'static str,
+    solver: &'static dyn Solver,
+}
+
+impl Day {
+    /// The day's solution, with its parsed input type erased.
+    pub fn solver(&self) -> &'static dyn Solver {
+        self.solver
+    }
+}
+
+macro_rules! days {
+    (year: $year:literal; $($number:literal => $module:ident::$solution:ident, $title:literal;)*) => {
+        /// Every implemented day of the year, in order.
+        pub const DAYS: &[$crate::aoc::Day] = &[
+            $($crate::aoc::Day {
+                year: $year,
+                number: $number,
+                title: $title,
+                solver: &$module::$solution,
+            },)*
+        ];
+    };
+}
+
+macro_rules! years {
+    ($($year:literal => $module:ident;)*) => {
+        /// Every year with solutions and its days, oldest first.
+        pub const YEARS: &[(usize, &[Day])] = &[$(($year, $module::DAYS),)*];
+    };
+}
+
+/// Advent of Code 2024
+pub mod year2024;
+
+years! {
+    2024 => year2024;
+}
+
+/// The most recent year with solutions, used when no year is given.
+pub fn latest_year() -> usize {
+    YEARS.last().map_or(2024, |(year, _)| *year)
+}
+
+/// The implemented days of a year, empty for years without solutions.
+pub fn days(year: usize) -> &'static [Day] {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map_or(&[], |(_, days)| days)
+}
+
+/// Looks up a registered day by year and number.
+pub fn find_day(year: usize, number: usize) -> Option<&'static Day> {
+    days(year).iter().find(|day| day.number == number)
+}
 
+/// A day's puzzle. The runner [`parse`](Solution::parse)s the input once and solves
+/// [`part1`](Solution::part1), [`part2`](Solution::part2) or both from a shared
+/// reference to it, so parts can run in any order, repeatedly or concurrently.
 pub trait Solution {
-    fn parse_input(&mut self);
+    /// The parsed puzzle input, shared by both parts. It may borrow from the
+    /// input text, which outlives it.
+    type Parsed<'a>: Send + Sync;
+
+    /// Parses the puzzle input, reporting malformed input as [`AocError`].
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+
+    /// Every problem with the input, checked before parsing. None by default.
+    fn validate(_input: &str) -> Vec<AocError> {
+        Vec::new()
+    }
 
-    fn part1(&mut self) -> u64 {
-        0
+    /// Solves part 1, [`Answer::Unsolved`] until implemented.
+    fn part1(_input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
     }
 
-    fn part2(&mut self) -> u64 {
-        0
+    /// Solves part 2, [`Answer::Unsolved`] until implemented.
+    fn part2(_input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    /// Names of the other implementations of a part, besides [`DEFAULT_VARIANT`].
+    fn variants(_part: usize) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Solves a part with one of its [`variants`](Solution::variants).
+    fn variant(_input: &Self::Parsed<'_>, part: usize, name: &str) -> Result<Answer> {
+        Err(AocError::InvalidInput(format!(
+            "No variant '{name}' for part {part}"
+        )))
     }
 }
 
-pub fn run_day<T: Solution + ?Sized>(day: &mut T) {
-    let start = Instant::now();
-    day.parse_input();
-    let parse_time = start.elapsed().as_millis();
-    println!("{:3}.{:03} Parsing", parse_time / 1000, parse_time % 1000);
+/// A [`Solution`] with its parsed input type erased, as kept in the registry.
+pub trait Solver: Sync {
+    /// Parses the puzzle input with [`Solution::parse`].
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Puzzle + 'a>>;
 
-    let start = Instant::now();
-    let p1 = day.part1();
-    let p1_time = start.elapsed();
-    print_solution(1, p1, p1_time);
+    /// Checks the puzzle input with [`Solution::validate`].
+    fn validate(&self, input: &str) -> Vec<AocError>;
+
+    /// The [`Solution::variants`] of a part.
+    fn variants(&self, part: usize) -> &'static [&'static str];
+}
+
+/// A parsed puzzle input, ready to solve either part any number of times.
+pub trait Puzzle: Send + Sync {
+    /// Solves part 1 or 2 with a variant, or the default one when `None`.
+    fn solve(&self, part: usize, variant: Option<&str>) -> Result<Answer>;
+}
+
+struct Parsed<'a, S: Solution>(S::Parsed<'a>);
+
+impl<S: Solution + Sync + 'static> Solver for S {
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Puzzle + 'a>> {
+        let parsed = <S as Solution>::parse(input)?;
+        Ok(Box::new(Parsed::<S>(parsed)))
+    }
+
+    fn validate(&self, input: &str) -> Vec<AocError> {
+        <S as Solution>::validate(input)
+    }
+
+    fn variants(&self, part: usize) -> &'static [&'static str] {
+        <S as Solution>::variants(part)
+    }
+}
+
+impl<S: Solution> Puzzle for Parsed<'_, S> {
+    fn solve(&self, part: usize, variant: Option<&str>) -> Result<Answer> {
+        match variant {
+            Some(name) if name != DEFAULT_VARIANT => S::variant(&self.0, part, name),
+            _ if part == 1 => S::part1(&self.0),
+            _ => S::part2(&self.0),
+        }
+    }
+}
+
+/// Reads the input of a day and lists every problem its
+/// [`validate`](Solution::validate) finds.
+pub fn check_input(day: &'static Day, input: &Input) -> Result<Vec<AocError>> {
+    let data = input.load(day.year, day.number)?;
+    Ok(problems(day, &data))
+}
+
+fn problems(day: &Day, data: &str) -> Vec<AocError> {
+    day.solver
+        .validate(data)
+        .into_iter()
+        .map(|err| err.in_day(day.number))
+        .collect()
+}
+
+/// Fails with the first problem in the input, before anything is parsed.
+fn validate(day: &Day, data: &str) -> Result<()> {
+    match problems(day, data).into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Name of the implementation in [`Solution::part1`] and [`Solution::part2`].
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// The outcome of running one part.
+pub struct PartResult {
+    /// 1 or 2.
+    pub part: usize,
+    /// The answer, or why the part failed or timed out.
+    pub answer: Result<Answer>,
+    /// Time spent in the part.
+    pub time: Duration,
+    /// Allocations made by the part, when [`RunOptions::memory`] is set.
+    pub memory: Option<Memory>,
+    /// The variant that solved the part, `None` for the default.
+    pub variant: Option<&'static str>,
+}
+
+/// Everything [`run_day`] learned about a day.
+pub struct Report {
+    /// The day that ran.
+    pub day: &'static Day,
+    /// Path the input was read from, or `-` for stdin.
+    pub input: String,
+    /// FNV-1a hash of the input, when it could be read.
+    pub input_hash: Option<String>,
+    /// Time spent in [`Solution::parse`].
+    pub parse_time: Duration,
+    /// Allocations made while parsing, when [`RunOptions::memory`] is set.
+    pub parse_memory: Option<Memory>,
+    /// Whether the input could be read and parsed.
+    pub parse: Result<()>,
+    /// Parts that ran, in order. Empty when parsing failed.
+    pub parts: Vec<PartResult>,
+}
+
+impl Report {
+    /// Parse time plus the time of every part.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|p| p.time).sum::<Duration>()
+    }
+
+    /// Allocations summed over parsing and parts, with the highest peak.
+    pub fn memory(&self) -> Option<Memory> {
+        let stages = self.parts.iter().map(|p| p.memory);
 
+        std::iter::once(self.parse_memory)
+            .chain(stages)
+            .flatten()
+            .reduce(|total, stage| Memory {
+                allocations: total.allocations + stage.allocations,
+                bytes: total.bytes + stage.bytes,
+                peak: total.peak.max(stage.peak),
+            })
+    }
+
+    /// The result of part 1 or 2, if it ran.
+    pub fn part(&self, part: usize) -> Option<&PartResult> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+
+    /// Every error in the report, with the part it came from (`None` for parsing).
+    pub fn errors(&self) -> Vec<(Option<usize>, &AocError)> {
+        let parse = self.parse.as_ref().err().map(|err| (None, err));
+        let parts = self
+            .parts
+            .iter()
+            .filter_map(|p| p.answer.as_ref().err().map(|err| (Some(p.part), err)));
+
+        parse.into_iter().chain(parts).collect()
+    }
+
+    fn cell(&self, part: usize) -> String {
+        match self.part(part).map(|p| &p.answer) {
+            Some(Ok(answer)) => answer.to_string(),
+            Some(Err(AocError::Timeout(_))) => String::from("TIMEOUT"),
+            Some(Err(_)) => String::from("error"),
+            None if self.parse.is_err() => String::from("error"),
+            None => String::from("skipped"),
+        }
+    }
+}
+
+/// Parses a day selection: a day, a range (`1-5`), a list (`1,3,7`) or `all`
+/// the implemented days of `year`.
+pub fn select_days(year: usize, selection: &str) -> std::result::Result<Vec<usize>, String> {
+    if selection == "all" {
+        return Ok(days(year).iter().map(|day| day.number).collect());
+    }
+
+    let mut days = Vec::new();
+
+    for item in selection.split(',') {
+        let parse = |n: &str| match n.trim().parse::<usize>() {
+            Ok(n) if (1..=25).contains(&n) => Ok(n),
+            _ => Err(format!("invalid day '{n}'")),
+        };
+
+        if let Some((from, to)) = item.split_once('-') {
+            let (from, to) = (parse(from)?, parse(to)?);
+            if from > to {
+                return Err(format!("invalid range '{item}'"));
+            }
+            days.extend(from..=to);
+        } else {
+            days.push(parse(item)?);
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+
+    Ok(days)
+}
+
+/// How [`run_day`] and [`run_days`] run solutions.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    /// Number of days solved in parallel by [`run_days`].
+    pub jobs: usize,
+    /// Give up on a part after this long.
+    pub timeout: Option<Duration>,
+    /// Count allocations, which needs [`Counting`] as the global allocator.
+    pub memory: bool,
+    /// Solve the parts that have this variant with it, and the others as usual.
+    pub variant: Option<String>,
+    /// Run only part 1 or 2 instead of both.
+    pub part: Option<usize>,
+}
+
+impl RunOptions {
+    /// The parts to run, in order.
+    pub fn parts(&self) -> Vec<usize> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            jobs: 1,
+            timeout: None,
+            memory: false,
+            variant: None,
+            part: None,
+        }
+    }
+}
+
+type Stage = (Result<Answer>, Duration, Option<Memory>);
+
+fn measure<T>(
+    memory: bool,
+    f: impl FnOnce() -> Result<T>,
+) -> (Result<T>, Duration, Option<Memory>) {
     let start = Instant::now();
-    let p2 = day.part2();
-    let p2_time = start.elapsed();
-    print_solution(2, p2, p2_time);
+
+    if memory {
+        let (answer, memory) = memory::track(f);
+        (answer, start.elapsed(), Some(memory))
+    } else {
+        let answer = f();
+        (answer, start.elapsed(), None)
+    }
 }
 
-fn print_solution(which: usize, output: u64, duration: Duration) {
+type Variants = [Option<&'static str>; 2];
+
+fn spawn_solution(
+    day: &'static Day,
+    data: String,
+    memory: bool,
+    variants: Variants,
+    parts: Vec<usize>,
+) -> Receiver<Stage> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        if let Err(err) = validate(day, &data) {
+            let _ = sender.send((Err(err), Duration::ZERO, None));
+            return;
+        }
+
+        let (parsed, time, allocated) = measure(memory, || day.solver.parse(&data));
+        let puzzle = match parsed {
+            Ok(puzzle) => puzzle,
+            Err(err) => {
+                let _ = sender.send((Err(err), time, allocated));
+                return;
+            }
+        };
+        if sender
+            .send((Ok(Answer::Unsolved), time, allocated))
+            .is_err()
+        {
+            return;
+        }
+
+        for part in parts {
+            let variant = variants[part - 1];
+            let stage = measure(memory, || puzzle.solve(part, variant));
+            if sender.send(stage).is_err() {
+                return;
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Reads the input and runs a day on a worker thread, stopping at the first
+/// timeout or panic.
+pub fn run_day(day: &'static Day, input: &Input, options: &RunOptions) -> Report {
+    let data = input.load(day.year, day.number);
+
+    let mut report = Report {
+        day,
+        input: input.source(day.year, day.number),
+        input_hash: data
+            .as_ref()
+            .ok()
+            .map(|data| format!("{:016x}", hash::fnv1a(data.as_bytes()))),
+        parse_time: Duration::ZERO,
+        parse_memory: None,
+        parse: Ok(()),
+        parts: Vec::new(),
+    };
+
+    let variants = match select_variants(day, options.variant.as_deref()) {
+        Ok(variants) => variants,
+        Err(err) => {
+            report.parse = Err(err);
+            return report;
+        }
+    };
+
+    let receiver = match data {
+        Ok(data) => spawn_solution(day, data, options.memory, variants, options.parts()),
+        Err(err) => {
+            report.parse = Err(err.in_day(day.number));
+            return report;
+        }
+    };
+
+    for stage in std::iter::once(0).chain(options.parts()) {
+        let received = match options.timeout {
+            Some(timeout) => receiver.recv_timeout(timeout),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        let (answer, time, memory) = match received {
+            Ok(received) => received,
+            Err(RecvTimeoutError::Timeout) => {
+                let timeout = options.timeout.unwrap_or_default();
+                (Err(AocError::Timeout(timeout)), timeout, None)
+            }
+            Err(RecvTimeoutError::Disconnected) => (Err(AocError::Panicked), Duration::ZERO, None),
+        };
+
+        let answer = answer.map_err(|err| err.in_day(day.number));
+        let stop = matches!(answer, Err(AocError::Timeout(_)) | Err(AocError::Panicked));
+
+        if stage == 0 {
+            report.parse_time = time;
+            report.parse_memory = memory;
+            report.parse = answer.map(|_| ());
+            if report.parse.is_err() {
+                break;
+            }
+        } else {
+            report.parts.push(PartResult {
+                part: stage,
+                answer,
+                time,
+                memory,
+                variant: variants[stage - 1],
+            });
+        }
+
+        if stop {
+            break;
+        }
+    }
+
+    report
+}
+
+/// The variant each part runs with, `None` for the default where a part lacks it.
+/// Fails when neither part has it, so a misspelled name isn't silently ignored.
+fn select_variants(day: &Day, variant: Option<&str>) -> Result<Variants> {
+    let Some(name) = variant.filter(|name| *name != DEFAULT_VARIANT) else {
+        return Ok([None, None]);
+    };
+
+    let variants = [1, 2].map(|part| {
+        day.solver
+            .variants(part)
+            .iter()
+            .find(|variant| **variant == name)
+            .copied()
+    });
+
+    if variants == [None, None] {
+        return Err(AocError::InvalidInput(format!(
+            "day {} has no variant '{name}'",
+            day.number
+        )));
+    }
+
+    Ok(variants)
+}
+
+/// Runs several days, up to [`RunOptions::jobs`] at a time, keeping their order.
+pub fn run_days(days: &[&'static Day], input: &Input, options: &RunOptions) -> Vec<Report> {
+    if options.jobs <= 1 || days.len() <= 1 {
+        return days
+            .iter()
+            .map(|day| run_day(day, input, options))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let reports: Mutex<Vec<Option<Report>>> = Mutex::new(days.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(i) else {
+                    break;
+                };
+
+                let report = run_day(day, input, options);
+                reports.lock().unwrap()[i] = Some(report);
+            });
+        }
+    });
+
+    reports
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Prints the answers and timings of a single day.
+pub fn print_report(report: &Report) {
+    println!("Day {}: {}", report.day.number, report.day.title);
+
+    let memory = format_memory(report.parse_memory);
+    match &report.parse {
+        Ok(()) => println!("{} Parsing{memory}", format_time(report.parse_time)),
+        Err(err) => println!(
+            "{} Parsing: error: {err}{memory}",
+            format_time(report.parse_time)
+        ),
+    }
+
+    report.parts.iter().for_each(print_solution);
+}
+
+fn print_solution(part: &PartResult) {
+    let which = match part.variant {
+        Some(variant) => format!("{} ({variant})", part.part),
+        None => part.part.to_string(),
+    };
+    let memory = format_memory(part.memory);
+
+    match &part.answer {
+        Ok(answer) => println!("{} Part {which}: {answer}{memory}", format_time(part.time)),
+        Err(err) => println!(
+            "{} Part {which}: error: {err}{memory}",
+            format_time(part.time)
+        ),
+    }
+}
+
+fn format_memory(memory: Option<Memory>) -> String {
+    match memory {
+        Some(memory) => format!(
+            " ({} allocations, {} allocated, {} peak)",
+            memory.allocations,
+            memory::format_bytes(memory.bytes),
+            memory::format_bytes(memory.peak),
+        ),
+        None => String::new(),
+    }
+}
+
+/// Prints a table of answers and timings, then any errors on stderr.
+pub fn print_summary(reports: &[Report]) {
+    let answers: Vec<(String, String)> = reports.iter().map(|r| (r.cell(1), r.cell(2))).collect();
+    let w1 = answers.iter().map(|a| a.0.len()).fold(6, usize::max);
+    let w2 = answers.iter().map(|a| a.1.len()).fold(6, usize::max);
+
+    println!(
+        "{:>3}  {:<w1$}  {:<w2$}  {:>7}  {:>7}  {:>7}  {:>7}",
+        "Day", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    for (report, (p1, p2)) in reports.iter().zip(&answers) {
+        println!(
+            "{:>3}  {p1:<w1$}  {p2:<w2$}  {}  {}  {}  {}",
+            report.day.number,
+            format_time(report.parse_time),
+            format_time(report.part(1).map_or(Duration::ZERO, |p| p.time)),
+            format_time(report.part(2).map_or(Duration::ZERO, |p| p.time)),
+            format_time(report.total_time()),
+        );
+    }
+
+    let total: Duration = reports.iter().map(Report::total_time).sum();
+    let pad = 3 + 2 + w1 + 2 + w2 + 2 + 3 * 9;
+    println!("{:<pad$}{}", "Total", format_time(total));
+
+    if reports.iter().any(|r| r.memory().is_some()) {
+        println!();
+        println!(
+            "{:>3}  {:>11}  {:>11}  {:>11}",
+            "Day", "Allocations", "Allocated", "Peak"
+        );
+
+        for report in reports {
+            let memory = report.memory().unwrap_or_default();
+            println!(
+                "{:>3}  {:>11}  {:>11}  {:>11}",
+                report.day.number,
+                memory.allocations,
+                memory::format_bytes(memory.bytes),
+                memory::format_bytes(memory.peak),
+            );
+        }
+    }
+
+    for report in reports {
+        for (part, err) in report.errors() {
+            match (part, err) {
+                (_, AocError::Parse { .. }) => eprintln!("{err}"),
+                (Some(part), _) => eprintln!("day {} part {part}: {err}", report.day.number),
+                (None, _) => eprintln!("day {}: {err}", report.day.number),
+            }
+        }
+    }
+}
+
+/// Prints PASS, FAIL or UNKNOWN per part, returning false on any FAIL.
+pub fn verify(report: &Report, answers: &Answers) -> bool {
+    let hash = report.input_hash.as_deref().unwrap_or("-");
+    println!("Day {}: {} ({hash})", report.day.number, report.day.title);
+
+    let mut passed = true;
+
+    for part in 1..=2 {
+        let status = answers.check(report, part);
+        let answer = match report.part(part).map(|p| &p.answer) {
+            Some(Ok(answer)) => answer.to_string(),
+            Some(Err(err)) => format!("error: {err}"),
+            None => match &report.parse {
+                Err(err) => format!("error: {err}"),
+                Ok(()) => Answer::Unsolved.to_string(),
+            },
+        };
+
+        match &status {
+            Status::Pass => println!("PASS    Part {part}: {answer}"),
+            Status::Fail(Some(expected)) => {
+                println!("FAIL    Part {part}: {answer} (expected {expected})")
+            }
+            Status::Fail(None) => println!("FAIL    Part {part}: {answer}"),
+            Status::Unknown => println!("UNKNOWN Part {part}: {answer}"),
+        }
+
+        passed &= !matches!(status, Status::Fail(_));
+    }
+
+    passed
+}
+
+/// Verifies a report against the day's known answers, saving the unknown ones
+/// when `save` is set. Returns false on any FAIL.
+pub fn verify_day(report: &Report, save: bool) -> Result<bool> {
+    let mut answers = Answers::load(report.day.year, report.day.number)?;
+    let passed = verify(report, &answers);
+
+    if save {
+        answers.record(report);
+        answers.save()?;
+    }
+
+    Ok(passed)
+}
+
+/// Prints reports in `format`: a report per day when there is one, a summary
+/// otherwise, with the wall clock time of a parallel run.
+pub fn print_reports(reports: &[Report], format: Format, wall: Duration, jobs: usize) {
+    match format {
+        Format::Json => println!("{}", output::reports_json(reports)),
+        Format::Csv => print!("{}", output::reports_csv(reports)),
+        Format::Text if reports.len() == 1 => print_report(&reports[0]),
+        Format::Text => {
+            print_summary(reports);
+            if jobs > 1 {
+                print_wall_clock(reports, wall, jobs);
+            }
+        }
+    }
+}
+
+/// Benches every day in turn, printing why a day could not be benched.
+/// Returns the benches and whether every day was.
+pub fn bench_days(
+    days: &[&'static Day],
+    input: &Input,
+    options: &BenchOptions,
+) -> (Vec<Bench>, bool) {
+    let mut passed = true;
+    let mut benches = Vec::new();
+
+    for &day in days {
+        match bench_day(day, input, options) {
+            Ok(bench) => benches.push(bench),
+            Err(err) => {
+                eprintln!("day {}: {err}", day.number);
+                passed = false;
+            }
+        }
+    }
+
+    (benches, passed)
+}
+
+/// Prints benches in `format`.
+pub fn print_benches(benches: &[Bench], format: Format) {
+    match format {
+        Format::Text => benches.iter().for_each(print_bench),
+        Format::Json => println!("{}", output::benches_json(benches)),
+        Format::Csv => print!("{}", output::benches_csv(benches)),
+    }
+}
+
+/// Prints the timing statistics of a bench.
+pub fn print_bench(bench: &Bench) {
+    println!(
+        "Day {}: {} ({}, {} iterations)",
+        bench.day.number, bench.day.title, bench.input_hash, bench.iterations
+    );
+    println!(
+        "{:<8}{:>12}{:>12}{:>12}{:>12}",
+        "", "min", "median", "mean", "stddev"
+    );
+
+    for (stage, stats) in bench.stages() {
+        let stage = match stage.strip_prefix("part") {
+            Some(n) => format!("Part {n}"),
+            None => String::from("Parsing"),
+        };
+
+        println!(
+            "{stage:<8}{:>12}{:>12}{:>12}{:>12}",
+            format_precise(stats.min),
+            format_precise(stats.median),
+            format_precise(stats.mean),
+            format_precise(stats.stddev),
+        );
+    }
+}
+
+/// Prints a bench against the last recorded baseline, flagging regressions.
+pub fn print_comparison(bench: &Bench, comparisons: &[Comparison], threshold: f64) {
+    println!(
+        "Day {}: {} ({}, {} iterations)",
+        bench.day.number, bench.day.title, bench.input_hash, bench.iterations
+    );
+
+    for comparison in comparisons {
+        let stage = match comparison.stage.strip_prefix("part") {
+            Some(n) => format!("Part {n}"),
+            None => String::from("Parsing"),
+        };
+        let current = format_precise(comparison.current);
+
+        match (&comparison.baseline, comparison.change) {
+            (Some(baseline), Some(change)) => {
+                let commit = baseline.commit.as_deref().unwrap_or("no commit");
+                let flag = if comparison.regression {
+                    format!("  REGRESSION (> {threshold}%)")
+                } else {
+                    String::new()
+                };
+                println!(
+                    "{stage:<8}{:>12} ->{current:>12}  {change:+7.1}%  ({commit}){flag}",
+                    format_precise(baseline.median),
+                )
+            }
+            _ => println!("{stage:<8}{:>12} ->{current:>12}  no baseline", "-"),
+        }
+    }
+}
+
+/// Prints the answer and relative time of every variant, returning false when a
+/// part's variants disagree.
+pub fn print_crosscheck(crosscheck: &Crosscheck) -> bool {
+    println!(
+        "Day {}: {} ({})",
+        crosscheck.day.number, crosscheck.day.title, crosscheck.input_hash
+    );
+
+    let width = crosscheck
+        .checks
+        .iter()
+        .map(|check| check.variant.len())
+        .fold(7, usize::max);
+    let mut agrees = true;
+
+    for part in 1..=2 {
+        for check in crosscheck.part(part) {
+            let answer = match &check.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("error: {err}"),
+            };
+            println!(
+                "Part {part}  {:<width$}{:>12}  {:>7.2}x  {answer}",
+                check.variant,
+                format_precise(check.time),
+                crosscheck.relative(check),
+            );
+        }
+
+        if !crosscheck.agrees(part) {
+            println!("MISMATCH Part {part}: the variants disagree");
+            agrees = false;
+        }
+    }
+
+    agrees
+}
+
+/// Prints the wall clock time of a parallel run against the summed time.
+pub fn print_wall_clock(reports: &[Report], wall: Duration, jobs: usize) {
+    let total: Duration = reports.iter().map(Report::total_time).sum();
+
+    println!(
+        "{} Wall clock with {jobs} jobs ({} summed, {:.2}x)",
+        format_time(wall),
+        format_time(total).trim_start(),
+        total.as_secs_f64() / wall.as_secs_f64().max(f64::EPSILON),
+    );
+}
+
+fn format_precise(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    match nanos {
+        0..1_000 => format!("{nanos} ns"),
+        1_000..1_000_000 => format!("{:.3} µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.3} ms", nanos as f64 / 1e6),
+        _ => format!("{:.3} s", nanos as f64 / 1e9),
+    }
+}
+
+fn format_time(duration: Duration) -> String {
     let ms = duration.as_millis();
     let sec_part = ms / 1000;
     let ms_part = ms % 1000;
 
-    println!("{sec_part:3}.{ms_part:03} Part {which}: {}", output);
+    format!("{sec_part:3}.{ms_part:03}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn days_are_unique_and_in_range() {
+        assert!(YEARS.windows(2).all(|w| w[0].0 < w[1].0));
+
+        for &(year, days) in YEARS {
+            for (i, day) in days.iter().enumerate() {
+                assert_eq!(day.year, year);
+                assert!((1..=25).contains(&day.number));
+                assert!(days[i + 1..].iter().all(|d| d.number != day.number));
+            }
+        }
+    }
+
+    #[test]
+    fn find_unimplemented_day() {
+        assert_eq!(
+            find_day(2024, 1).map(|d| d.title),
+            Some("Historian Hysteria")
+        );
+        assert!(find_day(2024, 25).is_none());
+        assert!(find_day(2015, 1).is_none());
+        assert!(days(2015).is_empty());
+        assert_eq!(latest_year(), 2024);
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        type Parsed<'a> = ();
+
+        fn parse(_input: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<Answer> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    static SLOW: Day = Day {
+        year: 2024,
+        number: 1,
+        title: "Slow",
+        solver: &Slow,
+    };
+
+    #[test]
+    fn run_day_timeout() {
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let report = run_day(&SLOW, &Input::Example, &options);
+        assert!(report.parse.is_ok());
+        assert!(matches!(report.parts[0].answer, Err(AocError::Timeout(_))));
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.cell(1), "TIMEOUT");
+        assert_eq!(report.cell(2), "skipped");
+    }
+
+    struct Stuck;
+
+    impl Solution for Stuck {
+        type Parsed<'a> = ();
+
+        fn parse(_input: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<Answer> {
+            Err(AocError::Unsolvable(String::from("Stuck")))
+        }
+
+        fn part2(_input: &()) -> Result<Answer> {
+            Ok(Answer::from(2usize))
+        }
+    }
+
+    static STUCK: Day = Day {
+        year: 2024,
+        number: 1,
+        title: "Stuck",
+        solver: &Stuck,
+    };
+
+    #[test]
+    fn run_day_continues_after_part_error() {
+        let report = run_day(&STUCK, &Input::Example, &RunOptions::default());
+        assert!(matches!(
+            report.parts[0].answer,
+            Err(AocError::Unsolvable(_))
+        ));
+        assert_eq!(report.cell(1), "error");
+        assert_eq!(report.cell(2), "2");
+    }
+
+    #[test]
+    fn run_day_variant_falls_back_to_default() {
+        let options = RunOptions {
+            variant: Some(String::from("string")),
+            ..Default::default()
+        };
+
+        let report = run_day(find_day(2024, 7).unwrap(), &Input::Example, &options);
+        let variants: Vec<_> = report.parts.iter().map(|p| p.variant).collect();
+        assert_eq!(variants, [None, Some("string")]);
+        assert_eq!(report.cell(2), "11387");
+    }
+
+    #[test]
+    fn run_day_rejects_unknown_variant() {
+        let options = RunOptions {
+            variant: Some(String::from("strnig")),
+            ..Default::default()
+        };
+        let report = run_day(find_day(2024, 7).unwrap(), &Input::Example, &options);
+        assert!(
+            matches!(&report.parse, Err(AocError::InvalidInput(reason)) if reason == "day 7 has no variant 'strnig'")
+        );
+        assert!(report.parts.is_empty());
+
+        let options = RunOptions {
+            variant: Some(String::from("string")),
+            ..Default::default()
+        };
+        let report = run_day(find_day(2024, 1).unwrap(), &Input::Example, &options);
+        assert!(report.parse.is_err());
+    }
+
+    #[test]
+    fn run_day_validates_input() {
+        let day = find_day(2024, 6).unwrap();
+        assert!(check_input(day, &Input::Example).unwrap().is_empty());
+
+        let path = std::env::temp_dir().join("aoc-2024-no-guard");
+        std::fs::write(&path, "...\n.#.\n").unwrap();
+        let report = run_day(day, &Input::File(path.clone()), &RunOptions::default());
+        std::fs::remove_file(path).unwrap();
+
+        assert!(matches!(report.parse, Err(AocError::InvalidInput(_))));
+        assert!(report.parts.is_empty());
+    }
+
+    #[test]
+    fn run_day_single_part() {
+        let day = find_day(2024, 6).unwrap();
+        let options = RunOptions {
+            part: Some(2),
+            ..Default::default()
+        };
+        let report = run_day(day, &Input::Example, &options);
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, 2);
+        assert_eq!(report.cell(1), "skipped");
+        assert_eq!(report.cell(2), "6");
+    }
+
+    #[test]
+    fn parts_do_not_depend_on_order() {
+        for day in days(2024) {
+            let input = Input::Example.load(day.year, day.number).unwrap();
+            let solve = |parts: &[usize]| {
+                let puzzle = day.solver().parse(&input).unwrap();
+                parts
+                    .iter()
+                    .map(|&part| puzzle.solve(part, None).unwrap())
+                    .collect::<Vec<Answer>>()
+            };
+
+            let both = solve(&[1, 2]);
+            assert_eq!(solve(&[1]), both[..1], "day {} part 1", day.number);
+            assert_eq!(solve(&[2]), both[1..], "day {} part 2", day.number);
+            assert_eq!(
+                solve(&[2, 1]),
+                [both[1].clone(), both[0].clone()],
+                "day {}",
+                day.number
+            );
+        }
+    }
+
+    #[test]
+    fn run_days_in_parallel_keeps_order() {
+        let days: Vec<&Day> = days(2024).iter().collect();
+        let options = RunOptions {
+            jobs: 4,
+            ..Default::default()
+        };
+        let reports = run_days(&days, &Input::Example, &options);
+        let numbers: Vec<usize> = reports.iter().map(|r| r.day.number).collect();
+        let expected: Vec<usize> = days.iter().map(|d| d.number).collect();
+        assert_eq!(numbers, expected);
+        assert!(reports.iter().all(|r| r.errors().is_empty()));
+    }
+
+    #[test]
+    fn select_days_ranges_and_lists() {
+        assert_eq!(select_days(2024, "7"), Ok(vec![7]));
+        assert_eq!(select_days(2024, "1-3,7,2"), Ok(vec![1, 2, 3, 7]));
+        assert_eq!(
+            select_days(2024, "all").map(|d| d.len()),
+            Ok(days(2024).len())
+        );
+        assert_eq!(select_days(2015, "all"), Ok(vec![]));
+        assert!(select_days(2024, "5-1").is_err());
+        assert!(select_days(2024, "26").is_err());
+        assert!(select_days(2024, "x").is_err());
+    }
 }
@@ -0,0 +1,75 @@
+use std::fmt;
+
+/// A part's answer. Integer types convert with `.into()`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Most answers: counts and sums.
+    Unsigned(u64),
+    /// Answers that can be negative.
+    Signed(i64),
+    /// Answers that overflow 64 bits.
+    Big(u128),
+    /// Non-numeric answers, e.g. a code or a comma-separated list.
+    Text(String),
+    /// The part is not implemented yet, shown as `-`.
+    #[default]
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => n.fmt(f),
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Big(n) => n.fmt(f),
+            Answer::Text(s) => s.fmt(f),
+            Answer::Unsolved => "-".fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $inner:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::$variant(n as $inner)
+            }
+        })*
+    };
+}
+
+impl_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+impl_from!(Signed, i64, i8, i16, i32, i64, isize);
+impl_from!(Big, u128, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-7i32).to_string(), "-7");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("1,2,3").to_string(), "1,2,3");
+        assert_eq!(Answer::Unsolved.to_string(), "-");
+    }
+
+    #[test]
+    fn answer_zero_is_solved() {
+        assert_ne!(Answer::from(0u64), Answer::Unsolved);
+        assert_eq!(Answer::default(), Answer::Unsolved);
+    }
+}
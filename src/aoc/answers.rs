@@ -0,0 +1,204 @@
+use super::{Answer, Report};
+use crate::error::{AocError, Result};
+use std::{collections::BTreeMap, fmt, fs, io, path::PathBuf};
+
+/// Known answers of a day, kept in `./answers/YYYY/dayNN.toml` and keyed by input hash.
+#[derive(Default)]
+pub struct Answers {
+    year: usize,
+    day: usize,
+    inputs: Sections,
+}
+
+/// How an answer compares with the known one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    /// It matches.
+    Pass,
+    /// It differs from the known answer, or the part failed.
+    Fail(Option<String>),
+    /// There is no known answer for this input.
+    Unknown,
+}
+
+impl Status {
+    /// Compares a report's answer of a part with `expected`, if there is one.
+    pub(super) fn of(report: &Report, part: usize, expected: Option<&str>) -> Status {
+        let answer = report.part(part).map(|p| &p.answer);
+
+        match (answer, expected) {
+            (Some(Ok(answer)), Some(expected)) if answer.to_string() == expected => Status::Pass,
+            (_, Some(expected)) => Status::Fail(Some(expected.to_string())),
+            (Some(Ok(_)), None) => Status::Unknown,
+            _ => Status::Fail(None),
+        }
+    }
+}
+
+impl Answers {
+    /// `./answers/YYYY/dayNN.toml`.
+    pub fn path(year: usize, day: usize) -> PathBuf {
+        PathBuf::from(format!("./answers/{year}/day{day:02}.toml"))
+    }
+
+    /// Loads the answers of a day, empty if the file doesn't exist.
+    pub fn load(year: usize, day: usize) -> Result<Answers> {
+        match fs::read_to_string(Answers::path(year, day)) {
+            Ok(data) => Answers::parse(year, day, &data),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers {
+                year,
+                day,
+                ..Default::default()
+            }),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn parse(year: usize, day: usize, data: &str) -> Result<Answers> {
+        Ok(Answers {
+            year,
+            day,
+            inputs: sections(data).map_err(|err| err.in_day(day))?,
+        })
+    }
+
+    /// The known answer of a part for the input with `hash`.
+    pub fn get(&self, hash: &str, part: usize) -> Option<&str> {
+        self.inputs.get(hash)?.get(&part).map(String::as_str)
+    }
+
+    /// Stores the answer of a part for the input with `hash`.
+    pub fn set(&mut self, hash: &str, part: usize, answer: &Answer) {
+        self.inputs
+            .entry(hash.to_string())
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    /// Stores the answers of a report that are not known yet, leaving the known ones.
+    pub fn record(&mut self, report: &Report) {
+        let Some(hash) = &report.input_hash else {
+            return;
+        };
+
+        for part in &report.parts {
+            if let Ok(answer) = &part.answer {
+                if *answer != Answer::Unsolved && self.check(report, part.part) == Status::Unknown {
+                    self.set(hash, part.part, answer);
+                }
+            }
+        }
+    }
+
+    /// Writes the answers back to [`Answers::path`].
+    pub fn save(&self) -> Result<()> {
+        let path = Answers::path(self.year, self.day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())?;
+
+        Ok(())
+    }
+
+    /// Compares a report's answer of a part with the known one.
+    pub fn check(&self, report: &Report, part: usize) -> Status {
+        let expected = report
+            .input_hash
+            .as_deref()
+            .and_then(|hash| self.get(hash, part));
+
+        Status::of(report, part, expected)
+    }
+}
+
+/// Answers by section and part, from `[section]` headers followed by
+/// `partN = "answer"` lines.
+pub(super) type Sections = BTreeMap<String, BTreeMap<usize, String>>;
+
+/// Parses the [`Sections`] shared by answer files and example manifests.
+pub(super) fn sections(data: &str) -> Result<Sections> {
+    let mut sections = Sections::new();
+    let mut current = None;
+
+    for (i, line) in data.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.entry(name.trim().to_string()).or_default();
+            current = Some(name.trim().to_string());
+            continue;
+        }
+
+        let invalid = |reason| AocError::parse(i + 1, 1, line, reason);
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| invalid("Expected 'partN = \"answer\"'"))?;
+        let part = key
+            .trim()
+            .strip_prefix("part")
+            .and_then(|n| n.parse().ok())
+            .ok_or_else(|| invalid("Expected 'part1' or 'part2'"))?;
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+        let section = current
+            .as_ref()
+            .ok_or_else(|| invalid("Expected a [section] header first"))?;
+
+        sections
+            .entry(section.clone())
+            .or_default()
+            .insert(part, value.to_string());
+    }
+
+    Ok(sections)
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Accepted answers for day {}, keyed by input hash",
+            self.day
+        )?;
+
+        for (hash, parts) in &self.inputs {
+            writeln!(f, "\n[{hash}]")?;
+            for (part, answer) in parts {
+                writeln!(f, "part{part} = \"{answer}\"")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn answers_round_trip() {
+        let mut answers = Answers::parse(2024, 3, "[abc]\npart1 = \"161\"\n").unwrap();
+        answers.set("def", 2, &Answer::from(-4i64));
+
+        let answers = Answers::parse(2024, 3, &answers.to_string()).unwrap();
+        assert_eq!(answers.get("abc", 1), Some("161"));
+        assert_eq!(answers.get("abc", 2), None);
+        assert_eq!(answers.get("def", 2), Some("-4"));
+    }
+
+    #[test]
+    fn answers_parse_error() {
+        assert!(Answers::parse(2024, 3, "part1 = \"161\"\n").is_err());
+        assert!(Answers::parse(2024, 3, "[abc]\nanswer = 3\n").is_err());
+    }
+}
@@ -0,0 +1,243 @@
+use super::Bench;
+use crate::error::{AocError, Result};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Slowdown, in percent, above which [`Baselines::compare`] flags a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// A recorded bench of one stage, a line of `./answers/YYYY/bench.history`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the commit that was benched, if any.
+    pub commit: Option<String>,
+    /// The benched day.
+    pub day: usize,
+    /// `parse`, `part1` or `part2`.
+    pub stage: String,
+    /// Hash of the benched input. Only equal inputs are compared.
+    pub input_hash: String,
+    /// Median time of the stage.
+    pub median: Duration,
+    /// Fastest time of the stage.
+    pub min: Duration,
+}
+
+/// A stage of a bench against its last baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// `parse`, `part1` or `part2`.
+    pub stage: String,
+    /// Median time of this bench.
+    pub current: Duration,
+    /// The last baseline of the stage on the same input.
+    pub baseline: Option<Baseline>,
+    /// Change of the median in percent, positive when slower.
+    pub change: Option<f64>,
+    /// Whether the change is above the threshold.
+    pub regression: bool,
+}
+
+/// The bench history of a year, appended to by every bench.
+pub struct Baselines {
+    year: usize,
+    entries: Vec<Baseline>,
+}
+
+/// Short hash of the current git commit, if in a repository.
+pub fn commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    (!commit.is_empty()).then_some(commit)
+}
+
+impl Baselines {
+    /// `./answers/YYYY/bench.history`.
+    pub fn path(year: usize) -> PathBuf {
+        PathBuf::from(format!("./answers/{year}/bench.history"))
+    }
+
+    /// Loads the history of a year, empty if the file doesn't exist.
+    pub fn load(year: usize) -> Result<Baselines> {
+        match fs::read_to_string(Baselines::path(year)) {
+            Ok(data) => Baselines::parse(year, &data),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baselines {
+                year,
+                entries: Vec::new(),
+            }),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn parse(year: usize, data: &str) -> Result<Baselines> {
+        let entries = data
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let invalid = |reason| AocError::parse(i + 1, 1, line, reason);
+                let fields: Vec<&str> = line.split('\t').collect();
+                let [timestamp, commit, day, stage, input_hash, median, min] = fields[..] else {
+                    return Err(invalid(
+                        "Expected 'timestamp\\tcommit\\tday\\tstage\\tinput_hash\\tmedian_ns\\tmin_ns'",
+                    ));
+                };
+                let nanos = |field: &str| {
+                    field
+                        .parse()
+                        .map(Duration::from_nanos)
+                        .map_err(|_| invalid("Invalid time"))
+                };
+
+                Ok(Baseline {
+                    timestamp: timestamp
+                        .parse()
+                        .map_err(|_| invalid("Invalid timestamp"))?,
+                    commit: (commit != "-").then(|| commit.to_string()),
+                    day: day.parse().map_err(|_| invalid("Invalid day"))?,
+                    stage: stage.to_string(),
+                    input_hash: input_hash.to_string(),
+                    median: nanos(median)?,
+                    min: nanos(min)?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Baselines { year, entries })
+    }
+
+    /// The most recent baseline of a stage on the same input.
+    pub fn last(&self, day: usize, stage: &str, input_hash: &str) -> Option<&Baseline> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|b| b.day == day && b.stage == stage && b.input_hash == input_hash)
+    }
+
+    /// Compares every stage of a bench with its last baseline.
+    pub fn compare(&self, bench: &Bench, threshold: f64) -> Vec<Comparison> {
+        bench
+            .stages()
+            .map(|(stage, stats)| {
+                let baseline = self.last(bench.day.number, &stage, &bench.input_hash);
+                let change = baseline
+                    .filter(|b| !b.median.is_zero())
+                    .map(|b| (stats.median.as_secs_f64() / b.median.as_secs_f64() - 1.0) * 100.0);
+
+                Comparison {
+                    stage,
+                    current: stats.median,
+                    baseline: baseline.cloned(),
+                    change,
+                    regression: change.is_some_and(|change| change > threshold),
+                }
+            })
+            .collect()
+    }
+
+    /// Appends every stage of a bench to the history.
+    pub fn record(&mut self, bench: &Bench, commit: Option<&str>) -> Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        let path = Baselines::path(self.year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        for (stage, stats) in bench.stages() {
+            let baseline = Baseline {
+                timestamp,
+                commit: commit.map(String::from),
+                day: bench.day.number,
+                stage,
+                input_hash: bench.input_hash.clone(),
+                median: stats.median,
+                min: stats.min,
+            };
+
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                baseline.timestamp,
+                baseline.commit.as_deref().unwrap_or("-"),
+                baseline.day,
+                baseline.stage,
+                baseline.input_hash,
+                baseline.median.as_nanos(),
+                baseline.min.as_nanos(),
+            )?;
+
+            self.entries.push(baseline);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::{bench::Stats, find_day};
+
+    fn bench(medians: [u64; 3]) -> Bench {
+        let stats = |nanos| Stats {
+            median: Duration::from_nanos(nanos),
+            ..Default::default()
+        };
+
+        Bench {
+            day: find_day(2024, 7).unwrap(),
+            input: String::from("./input/2024/day07"),
+            input_hash: String::from("abc"),
+            iterations: 10,
+            parse: stats(medians[0]),
+            parts: vec![(1, stats(medians[1])), (2, stats(medians[2]))],
+        }
+    }
+
+    #[test]
+    fn baseline_compare() {
+        let baselines = Baselines::parse(
+            2024,
+            "1\t-\t7\tparse\tabc\t100\t90\n\
+             1\t-\t7\tpart1\tabc\t1000\t900\n\
+             2\tdeadbee\t7\tpart1\tabc\t2000\t1900\n\
+             2\tdeadbee\t7\tpart2\tother\t10\t9\n",
+        )
+        .unwrap();
+
+        let last = baselines.last(7, "part1", "abc").unwrap();
+        assert_eq!(last.commit.as_deref(), Some("deadbee"));
+        assert_eq!(last.median, Duration::from_nanos(2000));
+
+        let comparisons = baselines.compare(&bench([105, 2500, 10]), 10.0);
+        assert!(comparisons[0]
+            .change
+            .is_some_and(|c| (c - 5.0).abs() < 1e-9));
+        assert!(!comparisons[0].regression);
+        assert!(comparisons[1]
+            .change
+            .is_some_and(|c| (c - 25.0).abs() < 1e-9));
+        assert!(comparisons[1].regression);
+        assert_eq!(comparisons[2].baseline, None);
+        assert!(!comparisons[2].regression);
+
+        assert!(Baselines::parse(2024, "1\t-\t7\tparse\tabc\tfast\t90\n").is_err());
+    }
+}
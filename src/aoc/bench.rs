@@ -0,0 +1,168 @@
+use super::{validate, Day, Input};
+use crate::{error::Result, utils::hash};
+use std::time::{Duration, Instant};
+
+/// Untimed runs before sampling.
+pub const WARMUP: usize = 1;
+
+/// How long [`bench_day`] samples a day.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// Maximum number of samples.
+    pub iterations: usize,
+    /// Stop sampling after this long, with at least one sample.
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            iterations: 1000,
+            budget: Duration::from_secs(3),
+        }
+    }
+}
+
+/// Statistics of the samples of a stage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Fastest sample.
+    pub min: Duration,
+    /// Median sample.
+    pub median: Duration,
+    /// Mean of the samples.
+    pub mean: Duration,
+    /// Population standard deviation of the samples.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Statistics of `samples`, all zero when empty.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Timings of many runs of a day.
+pub struct Bench {
+    /// The benched day.
+    pub day: &'static Day,
+    /// Path the input was read from, or `-` for stdin.
+    pub input: String,
+    /// FNV-1a hash of the input.
+    pub input_hash: String,
+    /// Number of samples taken.
+    pub iterations: usize,
+    /// Timings of [`Solution::parse`](super::Solution::parse).
+    pub parse: Stats,
+    /// Timings of parts 1 and 2.
+    pub parts: Vec<(usize, Stats)>,
+}
+
+impl Bench {
+    /// Parsing and the parts, named `parse`, `part1` and `part2`.
+    pub fn stages(&self) -> impl Iterator<Item = (String, &Stats)> {
+        [(String::from("parse"), &self.parse)]
+            .into_iter()
+            .chain(self.parts.iter().map(|(n, s)| (format!("part{n}"), s)))
+    }
+}
+
+fn sample(day: &Day, data: &str) -> Result<[Duration; 3]> {
+    let start = Instant::now();
+    let puzzle = day.solver().parse(data)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    puzzle.solve(1, None)?;
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    puzzle.solve(2, None)?;
+    let part2 = start.elapsed();
+
+    Ok([parse, part1, part2])
+}
+
+/// Parses and solves a day repeatedly and collects timings.
+pub fn bench_day(day: &'static Day, input: &Input, options: &BenchOptions) -> Result<Bench> {
+    let data = input.load(day.year, day.number)?;
+    validate(day, &data)?;
+    let run = || sample(day, &data).map_err(|err| err.in_day(day.number));
+
+    for _ in 0..WARMUP {
+        run()?;
+    }
+
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    let start = Instant::now();
+
+    while samples[0].len() < options.iterations.max(1)
+        && (samples[0].is_empty() || start.elapsed() < options.budget)
+    {
+        for (stage, time) in run()?.into_iter().enumerate() {
+            samples[stage].push(time);
+        }
+    }
+
+    Ok(Bench {
+        day,
+        input: input.source(day.year, day.number),
+        input_hash: format!("{:016x}", hash::fnv1a(data.as_bytes())),
+        iterations: samples[0].len(),
+        parse: Stats::from_samples(&samples[0]),
+        parts: vec![
+            (1, Stats::from_samples(&samples[1])),
+            (2, Stats::from_samples(&samples[2])),
+        ],
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::find_day;
+
+    #[test]
+    fn stats_from_samples() {
+        let samples: Vec<Duration> = [4, 2, 8, 6].map(Duration::from_nanos).to_vec();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_nanos(2));
+        assert_eq!(stats.median, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+    }
+
+    #[test]
+    fn bench_example_day() {
+        let options = BenchOptions {
+            iterations: 5,
+            budget: Duration::from_secs(1),
+        };
+        let bench = bench_day(find_day(2024, 1).unwrap(), &Input::Example, &options);
+        assert_eq!(bench.unwrap().iterations, 5);
+    }
+}
@@ -0,0 +1,108 @@
+use super::{validate, Answer, Day, Input, DEFAULT_VARIANT};
+use crate::{error::Result, utils::hash};
+use std::time::{Duration, Instant};
+
+/// One variant of a part run by [`crosscheck`].
+pub struct Check {
+    /// 1 or 2.
+    pub part: usize,
+    /// Name of the variant, [`DEFAULT_VARIANT`] for `part1` and `part2`.
+    pub variant: &'static str,
+    /// The answer, or why the variant failed.
+    pub answer: Result<Answer>,
+    /// Time spent in the variant.
+    pub time: Duration,
+}
+
+/// Every variant of a day run on the same input.
+pub struct Crosscheck {
+    /// The checked day.
+    pub day: &'static Day,
+    /// FNV-1a hash of the input.
+    pub input_hash: String,
+    /// Checks of part 1 and then part 2, the default variant first.
+    pub checks: Vec<Check>,
+}
+
+impl Crosscheck {
+    /// The checks of part 1 or 2.
+    pub fn part(&self, part: usize) -> impl Iterator<Item = &Check> {
+        self.checks.iter().filter(move |check| check.part == part)
+    }
+
+    /// Whether every variant of a part gave the same answer without failing.
+    pub fn agrees(&self, part: usize) -> bool {
+        let mut answers = self.part(part).map(|check| check.answer.as_ref().ok());
+
+        match answers.next() {
+            Some(Some(first)) => answers.all(|answer| answer == Some(first)),
+            Some(None) => false,
+            None => true,
+        }
+    }
+
+    /// Time of a check relative to the default variant of its part.
+    pub fn relative(&self, check: &Check) -> f64 {
+        let default = self.part(check.part).next().map_or(check.time, |c| c.time);
+        check.time.as_secs_f64() / default.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+/// Parses the input once and runs every variant of both parts on it.
+pub fn crosscheck(day: &'static Day, input: &Input) -> Result<Crosscheck> {
+    let data = input.load(day.year, day.number)?;
+    validate(day, &data)?;
+    let puzzle = day
+        .solver()
+        .parse(&data)
+        .map_err(|err| err.in_day(day.number))?;
+    let mut checks = Vec::new();
+
+    for part in 1..=2 {
+        let variants = day.solver().variants(part);
+
+        for variant in std::iter::once(DEFAULT_VARIANT).chain(variants.iter().copied()) {
+            let start = Instant::now();
+            let answer = puzzle.solve(part, Some(variant));
+            checks.push(Check {
+                part,
+                variant,
+                answer: answer.map_err(|err| err.in_day(day.number)),
+                time: start.elapsed(),
+            });
+        }
+    }
+
+    Ok(Crosscheck {
+        day,
+        input_hash: format!("{:016x}", hash::fnv1a(data.as_bytes())),
+        checks,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::find_day;
+
+    #[test]
+    fn crosscheck_day7_variants_agree() {
+        let day = find_day(2024, 7).unwrap();
+        let crosscheck = crosscheck(day, &Input::Example).unwrap();
+
+        let variants: Vec<_> = crosscheck.part(2).map(|check| check.variant).collect();
+        assert_eq!(variants, [DEFAULT_VARIANT, "string"]);
+        assert_eq!(crosscheck.part(1).count(), 1);
+        assert!(crosscheck.agrees(1) && crosscheck.agrees(2));
+    }
+
+    #[test]
+    fn crosscheck_disagreement() {
+        let day = find_day(2024, 7).unwrap();
+        let mut crosscheck = crosscheck(day, &Input::Example).unwrap();
+
+        crosscheck.checks[2].answer = Ok(Answer::Unsigned(0));
+        assert!(crosscheck.agrees(1));
+        assert!(!crosscheck.agrees(2));
+    }
+}
@@ -0,0 +1,137 @@
+use super::answers::{sections, Status};
+use super::{find_day, run_day, Input, Report, RunOptions};
+use crate::error::{AocError, Result};
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+/// An example input and its expected answers, listed in `./example/YYYY/manifest`.
+#[derive(Debug)]
+pub struct Example {
+    /// Year of the event.
+    pub year: usize,
+    /// Day the example belongs to.
+    pub day: usize,
+    /// File name in `./example/YYYY`, `dayNN` or `dayNN-name`.
+    pub name: String,
+    /// Expected answer of each part, only for the parts the example is meant for.
+    pub expected: BTreeMap<usize, String>,
+}
+
+impl Example {
+    /// `./example/YYYY/manifest`.
+    pub fn manifest(year: usize) -> PathBuf {
+        PathBuf::from(format!("./example/{year}/manifest"))
+    }
+
+    /// Every example of a year, empty if there is no manifest.
+    pub fn load(year: usize) -> Result<Vec<Example>> {
+        match fs::read_to_string(Example::manifest(year)) {
+            Ok(data) => Example::parse(year, &data),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn parse(year: usize, data: &str) -> Result<Vec<Example>> {
+        sections(data)?
+            .into_iter()
+            .map(|(name, expected)| {
+                let day = name
+                    .strip_prefix("day")
+                    .and_then(|rest| rest.split('-').next())
+                    .and_then(|number| number.parse().ok())
+                    .ok_or_else(|| {
+                        AocError::InvalidInput(format!(
+                            "Expected a [dayNN] or [dayNN-name] example, found [{name}]"
+                        ))
+                    })?;
+
+                Ok(Example {
+                    year,
+                    day,
+                    name,
+                    expected,
+                })
+            })
+            .collect()
+    }
+
+    /// The example file, `./example/YYYY/<name>`.
+    pub fn input(&self) -> Input {
+        Input::File(PathBuf::from(format!(
+            "./example/{}/{}",
+            self.year, self.name
+        )))
+    }
+
+    /// Runs the day on the example, only the part it is meant for if there is one.
+    pub fn run(&self) -> Result<Report> {
+        let day = find_day(self.year, self.day).ok_or_else(|| {
+            AocError::InvalidInput(format!("No day {} in {}", self.day, self.year))
+        })?;
+        let part = match self.expected.keys().collect::<Vec<_>>().as_slice() {
+            [part] => Some(**part),
+            _ => None,
+        };
+
+        Ok(run_day(
+            day,
+            &self.input(),
+            &RunOptions {
+                part,
+                ..Default::default()
+            },
+        ))
+    }
+
+    /// Compares a report's answer of a part with the expected one.
+    pub fn check(&self, report: &Report, part: usize) -> Status {
+        Status::of(report, part, self.expected.get(&part).map(String::as_str))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Runs an example of the manifest through [`run_day`] and checks every expected part.
+    fn check(year: usize, name: &str) {
+        let examples = Example::load(year).unwrap();
+        let example = examples.iter().find(|e| e.name == name).unwrap();
+        let report = example.run().unwrap();
+
+        if let Err(err) = &report.parse {
+            panic!("{name}: {err}");
+        }
+        for &part in example.expected.keys() {
+            let answer = report.part(part).map(|p| match &p.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => err.to_string(),
+            });
+            assert_eq!(
+                example.check(&report, part),
+                Status::Pass,
+                "{name} part {part} gave {answer:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn examples_manifest() {
+        let examples = Example::parse(
+            2024,
+            "[day03-part1]\npart1 = \"161\"\n\n[day03]\npart2 = \"48\"\n",
+        )
+        .unwrap();
+        let days: Vec<_> = examples.iter().map(|e| (e.day, e.name.as_str())).collect();
+        assert_eq!(days, [(3, "day03"), (3, "day03-part1")]);
+        assert_eq!(
+            examples[1].expected.get(&1).map(String::as_str),
+            Some("161")
+        );
+
+        assert!(Example::parse(2024, "[sample]\npart1 = \"1\"\n").is_err());
+        assert!(Example::load(2015).unwrap().is_empty());
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
@@ -0,0 +1,267 @@
+use super::Answer;
+use crate::error::{AocError, Result};
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The website's response to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer is right.
+    Correct,
+    /// The answer is wrong and too high.
+    TooHigh,
+    /// The answer is wrong and too low.
+    TooLow,
+    /// The answer is wrong.
+    Wrong,
+    /// Submitted too soon, with the time left to wait.
+    RateLimited(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// The response was not recognized.
+    Unknown,
+}
+
+impl Verdict {
+    /// What the verdict means, as shown after submitting.
+    pub fn message(&self) -> String {
+        match self {
+            Verdict::Correct => "That's the right answer!".to_string(),
+            Verdict::TooHigh => "Wrong: the answer is too high".to_string(),
+            Verdict::TooLow => "Wrong: the answer is too low".to_string(),
+            Verdict::Wrong => "Wrong answer".to_string(),
+            Verdict::RateLimited(wait) => format!(
+                "Rate limited: wait {}s before submitting again",
+                wait.as_secs()
+            ),
+            Verdict::WrongLevel => "That part is already solved or still locked".to_string(),
+            Verdict::Unknown => "Unrecognized response from the server".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too_high"),
+            Verdict::TooLow => write!(f, "too_low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "rate_limited:{}", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "wrong_level"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "wrong_level" => Ok(Verdict::WrongLevel),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => s
+                .strip_prefix("rate_limited:")
+                .and_then(|secs| secs.parse().ok())
+                .map(|secs| Verdict::RateLimited(Duration::from_secs(secs)))
+                .ok_or_else(|| format!("unknown verdict '{s}'")),
+        }
+    }
+}
+
+/// A submitted answer, a line of `./answers/YYYY/dayNN.history`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// 1 or 2.
+    pub part: usize,
+    /// The submitted answer.
+    pub answer: String,
+    /// The response to it.
+    pub verdict: Verdict,
+}
+
+/// Answers submitted for a day, so the same mistake is not submitted twice.
+pub struct History {
+    year: usize,
+    day: usize,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// `./answers/YYYY/dayNN.history`.
+    pub fn path(year: usize, day: usize) -> PathBuf {
+        PathBuf::from(format!("./answers/{year}/day{day:02}.history"))
+    }
+
+    /// Loads the history of a day, empty if the file doesn't exist.
+    pub fn load(year: usize, day: usize) -> Result<History> {
+        match fs::read_to_string(History::path(year, day)) {
+            Ok(data) => History::parse(year, day, &data),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History {
+                year,
+                day,
+                attempts: Vec::new(),
+            }),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn parse(year: usize, day: usize, data: &str) -> Result<History> {
+        let attempts = data
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let invalid = |reason| AocError::parse(i + 1, 1, line, reason).in_day(day);
+                let fields: Vec<&str> = line.splitn(4, '\t').collect();
+                let [timestamp, part, verdict, answer] = fields[..] else {
+                    return Err(invalid("Expected 'timestamp\\tpart\\tverdict\\tanswer'"));
+                };
+
+                Ok(Attempt {
+                    timestamp: timestamp
+                        .parse()
+                        .map_err(|_| invalid("Invalid timestamp"))?,
+                    part: part.parse().map_err(|_| invalid("Invalid part"))?,
+                    verdict: verdict.parse().map_err(|_| invalid("Invalid verdict"))?,
+                    answer: answer.to_string(),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(History {
+            year,
+            day,
+            attempts,
+        })
+    }
+
+    /// Attempts for a part, oldest first.
+    pub fn attempts(&self, part: usize) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |a| a.part == part)
+    }
+
+    /// Refuses answers that are known to be wrong: already solved parts, rejected
+    /// answers and numbers outside the too high and too low bounds.
+    pub fn check(&self, part: usize, answer: &Answer) -> std::result::Result<(), String> {
+        let answer = answer.to_string();
+        let value: Option<i128> = answer.parse().ok();
+
+        for attempt in self.attempts(part) {
+            let known: Option<i128> = attempt.answer.parse().ok();
+
+            match (attempt.verdict, value, known) {
+                (Verdict::Correct, _, _) => {
+                    return Err(format!(
+                        "part {part} was already solved with {}",
+                        attempt.answer
+                    ))
+                }
+                (Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong, _, _)
+                    if attempt.answer == answer =>
+                {
+                    return Err(format!(
+                        "{answer} was already rejected ({})",
+                        attempt.verdict
+                    ))
+                }
+                (Verdict::TooHigh, Some(value), Some(known)) if value >= known => {
+                    return Err(format!("{answer} is not below {known}, which is too high"))
+                }
+                (Verdict::TooLow, Some(value), Some(known)) if value <= known => {
+                    return Err(format!("{answer} is not above {known}, which is too low"))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends an attempt to the history.
+    pub fn record(&mut self, part: usize, answer: &Answer, verdict: Verdict) -> Result<()> {
+        let attempt = Attempt {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            part,
+            answer: answer.to_string(),
+            verdict,
+        };
+
+        let path = History::path(self.year, self.day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            attempt.timestamp, attempt.part, attempt.verdict, attempt.answer
+        )?;
+
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn history_refuses_known_answers() {
+        let history = History::parse(
+            2024,
+            7,
+            "1\t1\ttoo_high\t500\n2\t1\ttoo_low\t100\n3\t1\twrong\t300\n4\t2\tcorrect\t42\n",
+        )
+        .unwrap();
+
+        assert!(history.check(1, &Answer::from(200u64)).is_ok());
+        assert!(history.check(1, &Answer::from(300u64)).is_err());
+        assert!(history.check(1, &Answer::from(500u64)).is_err());
+        assert!(history.check(1, &Answer::from(600u64)).is_err());
+        assert!(history.check(1, &Answer::from(100u64)).is_err());
+        assert!(history.check(1, &Answer::from(-5i64)).is_err());
+        assert!(history.check(2, &Answer::from(43u64)).is_err());
+        assert!(history.check(1, &Answer::from("abc")).is_ok());
+    }
+
+    #[test]
+    fn history_verdict_round_trip() {
+        for verdict in [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::RateLimited(Duration::from_secs(83)),
+            Verdict::Unknown,
+        ] {
+            assert_eq!(verdict.to_string().parse(), Ok(verdict));
+        }
+        assert!(History::parse(2024, 1, "1\t1\tnope\t5\n").is_err());
+    }
+
+    #[test]
+    fn history_verdict_message() {
+        assert_eq!(
+            Verdict::RateLimited(Duration::from_secs(83)).message(),
+            "Rate limited: wait 83s before submitting again"
+        );
+        assert_eq!(Verdict::TooLow.message(), "Wrong: the answer is too low");
+    }
+}
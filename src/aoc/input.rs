@@ -0,0 +1,61 @@
+use crate::error::Result;
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where a day reads its input from.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// `./input/YYYY/dayNN`.
+    #[default]
+    Puzzle,
+    /// `./example/YYYY/dayNN`.
+    Example,
+    /// Any file.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl Input {
+    /// The file to read, `None` for stdin.
+    pub fn path(&self, year: usize, day: usize) -> Option<PathBuf> {
+        match self {
+            Input::Puzzle => Some(PathBuf::from(format!("./input/{year}/day{day:02}"))),
+            Input::Example => Some(PathBuf::from(format!("./example/{year}/day{day:02}"))),
+            Input::File(path) => Some(path.clone()),
+            Input::Stdin => None,
+        }
+    }
+
+    /// The file to read as text, `-` for stdin.
+    pub fn source(&self, year: usize, day: usize) -> String {
+        self.path(year, day)
+            .map_or(String::from("-"), |path| path.display().to_string())
+    }
+
+    /// Reads the whole input of a day.
+    pub fn load(&self, year: usize, day: usize) -> Result<String> {
+        let Some(path) = self.path(year, day) else {
+            let mut data = String::new();
+            io::stdin().read_to_string(&mut data)?;
+            return Ok(data);
+        };
+
+        fs::read_to_string(&path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())).into())
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Puzzle => write!(f, "input"),
+            Input::Example => write!(f, "example"),
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "-"),
+        }
+    }
+}
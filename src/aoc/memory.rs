@@ -0,0 +1,161 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// A global allocator that counts allocations on threads that ask for it, used by
+/// [`RunOptions::memory`](super::RunOptions::memory). Install it with
+/// `#[global_allocator] static ALLOCATOR: Counting = Counting;`.
+pub struct Counting;
+
+/// Allocations made during a stage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Memory {
+    /// Number of allocations, counting reallocations.
+    pub allocations: u64,
+    /// Total bytes allocated.
+    pub bytes: u64,
+    /// Highest number of bytes live at once, above what was live at the start.
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    enabled: bool,
+    allocations: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+const IDLE: Counters = Counters {
+    enabled: false,
+    allocations: 0,
+    bytes: 0,
+    live: 0,
+    peak: 0,
+};
+
+// Counters are per thread so parallel days don't see each other's allocations.
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(IDLE) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    let _ = COUNTERS.try_with(|cell| {
+        let mut counters = cell.get();
+        if !counters.enabled {
+            return;
+        }
+
+        if allocated > 0 {
+            counters.allocations += 1;
+            counters.bytes += allocated as u64;
+        }
+        counters.live += allocated as i64 - freed as i64;
+        counters.peak = counters.peak.max(counters.live);
+        cell.set(counters);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            record(new_size, layout.size());
+        }
+        new
+    }
+}
+
+// Only counts when `Counting` is the global allocator, otherwise everything is 0.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Memory) {
+    COUNTERS.with(|cell| {
+        cell.set(Counters {
+            enabled: true,
+            ..IDLE
+        })
+    });
+    let value = f();
+    let counters = COUNTERS.with(|cell| cell.replace(IDLE));
+
+    let memory = Memory {
+        allocations: counters.allocations,
+        bytes: counters.bytes,
+        peak: counters.peak.max(0) as u64,
+    };
+
+    (value, memory)
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn memory_track() {
+        let (data, memory) = track(|| vec![0u8; 4096]);
+        assert_eq!(data.len(), 4096);
+        assert_eq!(memory.allocations, 1);
+        assert_eq!(memory.bytes, 4096);
+        assert_eq!(memory.peak, 4096);
+
+        let ((), memory) = track(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(8);
+            v.extend(0..8);
+            drop(v);
+            let _ = vec![0u8; 16];
+        });
+        assert_eq!(memory.allocations, 2);
+        assert_eq!(memory.bytes, 64 + 16);
+        assert_eq!(memory.peak, 64);
+
+        let ((), memory) = track(|| drop(data));
+        assert_eq!(memory, Memory::default());
+    }
+
+    #[test]
+    fn memory_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,284 @@
+use super::{Answer, Bench, Memory, Report};
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+/// Output format of `run` and `bench`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable tables.
+    #[default]
+    Text,
+    /// A JSON array with an object per day.
+    Json,
+    /// CSV with a row per part or stage.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format '{s}'")),
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+fn json_option(s: Option<String>) -> String {
+    s.map_or(String::from("null"), |s| json_string(&s))
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn json_memory(memory: Option<Memory>) -> String {
+    match memory {
+        Some(memory) => format!(
+            "{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}",
+            memory.allocations, memory.bytes, memory.peak
+        ),
+        None => String::from("null"),
+    }
+}
+
+fn nanos(duration: Duration) -> u128 {
+    duration.as_nanos()
+}
+
+fn answer_text(answer: &Answer) -> Option<String> {
+    match answer {
+        Answer::Unsolved => None,
+        answer => Some(answer.to_string()),
+    }
+}
+
+/// Reports as a JSON array.
+pub fn reports_json(reports: &[Report]) -> String {
+    let days: Vec<String> = reports
+        .iter()
+        .map(|report| {
+            let parts: Vec<String> = report
+                .parts
+                .iter()
+                .map(|part| {
+                    let (answer, error) = match &part.answer {
+                        Ok(answer) => (answer_text(answer), None),
+                        Err(err) => (None, Some(err.to_string())),
+                    };
+
+                    format!(
+                        "{{\"part\":{},\"variant\":{},\"answer\":{},\"time_ns\":{},\"memory\":{},\"error\":{}}}",
+                        part.part,
+                        json_option(part.variant.map(String::from)),
+                        json_option(answer),
+                        nanos(part.time),
+                        json_memory(part.memory),
+                        json_option(error),
+                    )
+                })
+                .collect();
+
+            format!(
+                "{{\"year\":{},\"day\":{},\"title\":{},\"input\":{},\"input_hash\":{},\"parse_ns\":{},\"parse_memory\":{},\"total_ns\":{},\"error\":{},\"parts\":[{}]}}",
+                report.day.year,
+                report.day.number,
+                json_string(report.day.title),
+                json_string(&report.input),
+                json_option(report.input_hash.clone()),
+                nanos(report.parse_time),
+                json_memory(report.parse_memory),
+                nanos(report.total_time()),
+                json_option(report.parse.as_ref().err().map(|err| err.to_string())),
+                parts.join(","),
+            )
+        })
+        .collect();
+
+    format!("[{}]", days.join(","))
+}
+
+/// Reports as CSV with a row per part, or per day when parsing failed.
+pub fn reports_csv(reports: &[Report]) -> String {
+    let mut out = String::from("year,day,part,answer,parse_ns,time_ns,input,input_hash,error\n");
+
+    for report in reports {
+        let hash = report.input_hash.as_deref().unwrap_or("");
+
+        if let Err(err) = &report.parse {
+            writeln!(
+                out,
+                "{},{},,,{},,{},{hash},{}",
+                report.day.year,
+                report.day.number,
+                nanos(report.parse_time),
+                csv_field(&report.input),
+                csv_field(&err.to_string()),
+            )
+            .unwrap();
+        }
+
+        for part in &report.parts {
+            let (answer, error) = match &part.answer {
+                Ok(answer) => (answer_text(answer).unwrap_or_default(), String::new()),
+                Err(err) => (String::new(), err.to_string()),
+            };
+
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{hash},{}",
+                report.day.year,
+                report.day.number,
+                part.part,
+                csv_field(&answer),
+                nanos(report.parse_time),
+                nanos(part.time),
+                csv_field(&report.input),
+                csv_field(&error),
+            )
+            .unwrap();
+        }
+    }
+
+    out
+}
+
+/// Benches as a JSON array.
+pub fn benches_json(benches: &[Bench]) -> String {
+    let days: Vec<String> = benches
+        .iter()
+        .map(|bench| {
+            let stages: Vec<String> = bench
+                .stages()
+                .map(|(stage, stats)| {
+                    format!(
+                        "{{\"stage\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+                        json_string(&stage),
+                        nanos(stats.min),
+                        nanos(stats.median),
+                        nanos(stats.mean),
+                        nanos(stats.stddev),
+                    )
+                })
+                .collect();
+
+            format!(
+                "{{\"year\":{},\"day\":{},\"title\":{},\"input\":{},\"input_hash\":{},\"iterations\":{},\"stages\":[{}]}}",
+                bench.day.year,
+                bench.day.number,
+                json_string(bench.day.title),
+                json_string(&bench.input),
+                json_string(&bench.input_hash),
+                bench.iterations,
+                stages.join(","),
+            )
+        })
+        .collect();
+
+    format!("[{}]", days.join(","))
+}
+
+/// Benches as CSV with a row per stage.
+pub fn benches_csv(benches: &[Bench]) -> String {
+    let mut out = String::from(
+        "year,day,stage,iterations,min_ns,median_ns,mean_ns,stddev_ns,input,input_hash\n",
+    );
+
+    for bench in benches {
+        for (stage, stats) in bench.stages() {
+            writeln!(
+                out,
+                "{},{},{stage},{},{},{},{},{},{},{}",
+                bench.day.year,
+                bench.day.number,
+                bench.iterations,
+                nanos(stats.min),
+                nanos(stats.median),
+                nanos(stats.mean),
+                nanos(stats.stddev),
+                csv_field(&bench.input),
+                bench.input_hash,
+            )
+            .unwrap();
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::{find_day, run_day, Input, RunOptions};
+
+    #[test]
+    fn output_escaping() {
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("42"), "42");
+    }
+
+    #[test]
+    fn output_reports() {
+        let report = run_day(
+            find_day(2024, 9).unwrap(),
+            &Input::Example,
+            &Default::default(),
+        );
+        let json = reports_json(&[report]);
+        assert!(json.starts_with("[{\"year\":2024,\"day\":9,"));
+        assert!(json.contains("\"input\":\"./example/2024/day09\""));
+        assert!(json.contains("{\"part\":1,\"variant\":null,\"answer\":\"1928\","));
+        assert!(json.contains("{\"part\":2,\"variant\":null,\"answer\":null,"));
+        assert!(json.contains("\"parse_memory\":null,"));
+
+        let options = RunOptions {
+            memory: true,
+            ..Default::default()
+        };
+        let json = reports_json(&[run_day(
+            find_day(2024, 9).unwrap(),
+            &Input::Example,
+            &options,
+        )]);
+        assert!(json.contains("\"parse_memory\":{\"allocations\":"));
+        assert!(json.contains("\"memory\":{\"allocations\":"));
+
+        let report = run_day(
+            find_day(2024, 9).unwrap(),
+            &Input::Example,
+            &Default::default(),
+        );
+        let csv = reports_csv(&[report]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("2024,9,1,1928,"));
+        assert!(lines[2].starts_with("2024,9,2,,"));
+    }
+}
@@ -0,0 +1,57 @@
+use super::{run_day, Answer, Answers, Day, History, Input, PartResult, RunOptions, Verdict};
+use crate::client::Client;
+use crate::error::{AocError, Result};
+
+/// Solves a part on the puzzle input and submits the answer, unless the history
+/// already rules it out.
+///
+/// The attempt is recorded in the [`History`] and a correct answer is saved to
+/// the [`Answers`]. `base_url` defaults to the website.
+pub fn submit(
+    day: &'static Day,
+    part: usize,
+    options: &RunOptions,
+    base_url: Option<&str>,
+) -> Result<Verdict> {
+    let n = day.number;
+    let options = RunOptions {
+        part: Some(part),
+        ..options.clone()
+    };
+    let report = run_day(day, &Input::Puzzle, &options);
+    let answer = match report.parts.into_iter().find(|p| p.part == part) {
+        Some(PartResult {
+            answer: Ok(Answer::Unsolved),
+            ..
+        }) => {
+            return Err(AocError::NotSubmitted(format!(
+                "day {n} part {part} is not solved yet"
+            )))
+        }
+        Some(PartResult { answer, .. }) => answer?,
+        None => {
+            report.parse?;
+            return Err(AocError::NotSubmitted(format!(
+                "day {n} part {part} did not run"
+            )));
+        }
+    };
+
+    let mut history = History::load(day.year, n)?;
+    history
+        .check(part, &answer)
+        .map_err(AocError::NotSubmitted)?;
+
+    let client = Client::from_env(base_url)?;
+    println!("Submitting {answer} for {} day {n} part {part}", day.year);
+    let verdict = client.submit(day.year, n, part, &answer.to_string())?;
+    history.record(part, &answer, verdict)?;
+
+    if let (Verdict::Correct, Some(hash)) = (verdict, &report.input_hash) {
+        let mut answers = Answers::load(day.year, n)?;
+        answers.set(hash, part, &answer);
+        answers.save()?;
+    }
+
+    Ok(verdict)
+}
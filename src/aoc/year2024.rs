@@ -0,0 +1,31 @@
+/// Day 1: Historian Hysteria
+pub mod day01;
+/// Day 2: Red-Nosed Reports
+pub mod day02;
+/// Day 3: Mull It Over
+pub mod day03;
+/// Day 4: Ceres Search
+pub mod day04;
+/// Day 5: Print Queue
+pub mod day05;
+/// Day 6: Guard Gallivant
+pub mod day06;
+/// Day 7: Bridge Repair
+pub mod day07;
+/// Day 8: Resonant Collinearity
+pub mod day08;
+/// Day 9: Disk Fragmenter
+pub mod day09;
+
+days! {
+    year: 2024;
+    1 => day01::Day1, "Historian Hysteria";
+    2 => day02::Day2, "Red-Nosed Reports";
+    3 => day03::Day3, "Mull It Over";
+    4 => day04::Day4, "Ceres Search";
+    5 => day05::Day5, "Print Queue";
+    6 => day06::Day6, "Guard Gallivant";
+    7 => day07::Day7, "Bridge Repair";
+    8 => day08::Day8, "Resonant Collinearity";
+    9 => day09::Day9, "Disk Fragmenter";
+}
@@ -0,0 +1,190 @@
+use crate::aoc::{Answer, Solution};
+use crate::{
+    error::{AocError, Result},
+    utils::parser,
+};
+use std::collections::HashMap;
+
+type Column = Vec<i64>;
+type Matrix = Vec<Column>;
+type Counter = HashMap<u64, u64>;
+
+/// Solution of [Day 1: Historian Hysteria](https://adventofcode.com/2024/day/1).
+pub struct Day1;
+
+/// The two lists of location IDs, each sorted.
+pub struct Lists {
+    columns: Matrix,
+}
+
+impl Lists {
+    fn new(pairs: Vec<(i64, i64)>) -> Lists {
+        let mut columns: Matrix = vec![vec![]; 2];
+
+        for (n1, n2) in pairs {
+            columns[0].push(n1);
+            columns[1].push(n2);
+        }
+
+        columns[0].sort_unstable();
+        columns[1].sort_unstable();
+
+        Lists { columns }
+    }
+
+    fn get_distance(&self) -> i64 {
+        self.columns[0]
+            .iter()
+            .zip(&self.columns[1])
+            .map(|(&a, &b)| (a - b).abs())
+            .sum()
+    }
+
+    fn count_numbers(numbers: &[i64]) -> Counter {
+        let mut count = HashMap::new();
+
+        for &n in numbers {
+            *count.entry(n as u64).or_insert(0) += 1;
+        }
+
+        count
+    }
+
+    fn get_similarity(&self) -> u64 {
+        let count1 = Lists::count_numbers(&self.columns[0]);
+        let count2 = Lists::count_numbers(&self.columns[1]);
+
+        count1.iter().fold(0, |total, (&n, &times1)| {
+            if let Some(&times2) = count2.get(&n) {
+                total + n * times1 * times2
+            } else {
+                total
+            }
+        })
+    }
+}
+
+impl Day1 {
+    fn parse_line(i: usize, line: &str) -> Result<(i64, i64)> {
+        let mut nums = line.split_whitespace();
+
+        let n1 = nums
+            .next()
+            .ok_or_else(|| AocError::parse(i + 1, 1, line, "Expected first number"))?;
+        let n2 = nums.next().ok_or_else(|| {
+            AocError::parse(i + 1, line.len() + 1, line, "Expected second number")
+        })?;
+
+        Ok((
+            parser::number(i + 1, line, n1)?,
+            parser::number(i + 1, line, n2)?,
+        ))
+    }
+}
+
+impl Solution for Day1 {
+    type Parsed<'a> = Lists;
+
+    fn parse(input: &str) -> Result<Lists> {
+        let pairs: Vec<(i64, i64)> = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Day1::parse_line(i, line))
+            .collect::<Result<_>>()?;
+
+        Ok(Lists::new(pairs))
+    }
+
+    fn validate(input: &str) -> Vec<AocError> {
+        let mut problems = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            if let Err(err) = Day1::parse_line(i, line) {
+                problems.push(err);
+                continue;
+            }
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if let Some(extra) = tokens.get(2) {
+                let column = parser::column(line, extra);
+                problems.push(AocError::parse(
+                    i + 1,
+                    column,
+                    extra,
+                    "Expected two numbers",
+                ));
+            }
+            for token in tokens.iter().take(2).filter(|t| t.starts_with('-')) {
+                let column = parser::column(line, token);
+                problems.push(AocError::parse(
+                    i + 1,
+                    column,
+                    token,
+                    "Expected a location ID >= 0",
+                ));
+            }
+        }
+
+        problems
+    }
+
+    fn part1(lists: &Lists) -> Result<Answer> {
+        Ok(lists.get_distance().into())
+    }
+
+    fn part2(lists: &Lists) -> Result<Answer> {
+        Ok(lists.get_similarity().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_day1_part1() {
+        let lists = Day1::parse(&fs::read_to_string("./example/2024/day01").unwrap()).unwrap();
+        assert_eq!(lists.get_distance(), 11);
+    }
+
+    #[test]
+    fn test_day1_part2_count() {
+        let lists = Day1::parse(&fs::read_to_string("./example/2024/day01").unwrap()).unwrap();
+
+        let matrix1 = Lists::count_numbers(&lists.columns[0]);
+        assert_eq!(matrix1.get(&3), Some(&3));
+
+        let matrix1 = Lists::count_numbers(&lists.columns[1]);
+        assert_eq!(matrix1.get(&9), Some(&1));
+    }
+
+    #[test]
+    fn test_day1_part2() {
+        let lists = Day1::parse(&fs::read_to_string("./example/2024/day01").unwrap()).unwrap();
+        assert_eq!(lists.get_similarity(), 31);
+    }
+
+    #[test]
+    fn day1_validate() {
+        assert!(Day1::validate(&fs::read_to_string("./example/2024/day01").unwrap()).is_empty());
+
+        let problems = Day1::validate("3   4\n4\n-1   3\n1   2   3\nx   1\n");
+        let lines: Vec<usize> = problems
+            .iter()
+            .map(|err| match err {
+                AocError::Parse { line, .. } => *line,
+                _ => panic!("Expected a parse error"),
+            })
+            .collect();
+        assert_eq!(lines, [2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_day1_parse_error() {
+        match Day1::parse("3   4\n4   x\n") {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 5)),
+            _ => panic!("Expected a parse error"),
+        }
+    }
+}
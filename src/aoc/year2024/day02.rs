@@ -0,0 +1,288 @@
+use crate::{
+    error::{AocError, Result},
+    utils::parser,
+};
+
+use crate::aoc::{Answer, Solution};
+
+type Report = Vec<i64>;
+
+/// Solution of [Day 2: Red-Nosed Reports](https://adventofcode.com/2024/day/2).
+pub struct Day2;
+
+#[derive(PartialEq, Debug)]
+enum Dir {
+    Increasing,
+    Decreasing,
+}
+
+impl Day2 {
+    fn is_safe_part1(report: &[i64]) -> bool {
+        if report.len() < 2 {
+            return false;
+        }
+
+        let mut dir: Option<Dir> = None;
+
+        for pair in report.windows(2) {
+            if !Day2::is_valid_pair(&mut dir, pair[0], pair[1]) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn get_dir(current: i64, next: i64) -> Dir {
+        if current - next < 0 {
+            Dir::Increasing
+        } else {
+            Dir::Decreasing
+        }
+    }
+
+    fn check_diff(current: i64, next: i64) -> bool {
+        let diff = next - current;
+        let abs_diff = diff.abs();
+
+        if abs_diff == 0 || abs_diff > 3 {
+            return false;
+        }
+
+        true
+    }
+
+    fn is_valid_pair(dir: &mut Option<Dir>, current: i64, next: i64) -> bool {
+        if !Day2::check_diff(current, next) {
+            return false;
+        }
+
+        let current_dir = Day2::get_dir(current, next);
+
+        if let Some(prev_dir) = &dir {
+            if prev_dir != &current_dir {
+                return false;
+            }
+        } else {
+            *dir = Some(current_dir);
+        }
+
+        true
+    }
+
+    fn is_valid_report(report: &[i64]) -> (bool, usize) {
+        if report.len() < 2 {
+            return (false, 0);
+        }
+
+        let mut dir: Option<Dir> = None;
+
+        for (i, pair) in report.windows(2).enumerate() {
+            if !Day2::is_valid_pair(&mut dir, pair[0], pair[1]) {
+                return (false, i);
+            }
+        }
+
+        (true, 0)
+    }
+
+    fn remove_index<T: Copy>(slice: &[T], index: usize) -> Vec<T> {
+        let mut result = Vec::with_capacity(slice.len() - 1);
+        result.extend_from_slice(&slice[..index]);
+        result.extend_from_slice(&slice[index + 1..]);
+        result
+    }
+
+    fn is_safe_part2(r: &[i64]) -> bool {
+        let (valid, i) = Day2::is_valid_report(r);
+        if valid {
+            return true;
+        }
+
+        if i == 1 && Day2::is_valid_report(&r[1..]).0 {
+            return true;
+        }
+
+        if Day2::is_valid_report(&Day2::remove_index(r, i)).0 {
+            return true;
+        }
+
+        if i + 1 < r.len() && Day2::is_valid_report(&Day2::remove_index(r, i + 1)).0 {
+            return true;
+        }
+
+        false
+    }
+}
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Vec<Report>> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                line.split_whitespace()
+                    .map(|s| parser::number(i + 1, line, s))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn validate(input: &str) -> Vec<AocError> {
+        let mut problems = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                problems.push(AocError::parse(i + 1, 1, line, "Empty report"));
+            }
+            for token in line.split_whitespace() {
+                if let Err(err) = parser::number::<i64>(i + 1, line, token) {
+                    problems.push(err);
+                }
+            }
+        }
+
+        problems
+    }
+
+    fn part1(reports: &Vec<Report>) -> Result<Answer> {
+        Ok(reports
+            .iter()
+            .filter(|&r| Day2::is_safe_part1(r))
+            .count()
+            .into())
+    }
+
+    fn part2(reports: &Vec<Report>) -> Result<Answer> {
+        Ok(reports
+            .iter()
+            .filter(|&r| Day2::is_safe_part2(r))
+            .count()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::prop::{self, Rng};
+    use std::fs;
+
+    fn random_reports(rng: &mut Rng) -> Vec<Report> {
+        (0..rng.range(1, 10))
+            .map(|_| {
+                let mut level = rng.range(1, 20) as i64;
+                let step = if rng.chance(0.5) { 1 } else { -1 };
+                let len = if rng.chance(0.1) { 0 } else { rng.range(3, 8) };
+                (0..len)
+                    .map(|_| {
+                        level = (level + step * rng.range(0, 4) as i64).max(1);
+                        // An occasional jump the other way breaks the report.
+                        if rng.chance(0.2) {
+                            level = (level - step * rng.range(1, 5) as i64).max(1);
+                        }
+                        level
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn brute_force_safe(report: &[i64]) -> bool {
+        let diffs: Vec<i64> = report.windows(2).map(|w| w[1] - w[0]).collect();
+        !diffs.is_empty()
+            && (diffs.iter().all(|d| (1..=3).contains(d))
+                || diffs.iter().all(|d| (-3..=-1).contains(d)))
+    }
+
+    fn brute_force_dampened(report: &[i64]) -> bool {
+        brute_force_safe(report)
+            || (0..report.len()).any(|i| brute_force_safe(&Day2::remove_index(report, i)))
+    }
+
+    fn matches_brute_force(reports: &[Report]) -> std::result::Result<(), String> {
+        let input: String = reports
+            .iter()
+            .map(|r| {
+                r.iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    + "\n"
+            })
+            .collect();
+
+        let parsed = Day2::parse(&input).map_err(|err| err.to_string())?;
+
+        let safe = reports.iter().filter(|r| brute_force_safe(r)).count();
+        let dampened = reports.iter().filter(|r| brute_force_dampened(r)).count();
+        let answers = (Day2::part1(&parsed).unwrap(), Day2::part2(&parsed).unwrap());
+
+        if answers != (safe.into(), dampened.into()) {
+            return Err(format!(
+                "expected ({safe}, {dampened}), got ({}, {}) for\n{input}",
+                answers.0, answers.1
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn day2_matches_brute_force() {
+        prop::check("day2", random_reports, |reports| {
+            matches_brute_force(reports)
+        });
+    }
+
+    #[test]
+    fn test_day2_is_safe() {
+        let report: Report = vec![7, 6, 4, 2, 1];
+        assert!(Day2::is_safe_part1(&report));
+    }
+
+    #[test]
+    fn test_day2_is_not_safe() {
+        let report: Report = vec![1, 2, 7, 8, 9];
+        assert!(!Day2::is_safe_part1(&report));
+    }
+
+    #[test]
+    fn test_day2_part1_example() {
+        let reports = Day2::parse(&fs::read_to_string("./example/2024/day02").unwrap()).unwrap();
+        let count: usize = reports
+            .iter()
+            .map(|r| Day2::is_safe_part1(r))
+            .filter(|&v| v)
+            .count();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_day2_is_safe_part2() {
+        let r: Report = vec![24, 20, 25, 26];
+        assert!(Day2::is_safe_part2(&r));
+    }
+
+    #[test]
+    fn test_day2_is_not_safe_part2() {
+        let r: Report = vec![65, 68, 71, 71, 72, 79];
+        assert!(!Day2::is_safe_part2(&r));
+    }
+
+    #[test]
+    fn day2_validate() {
+        assert!(Day2::validate(&fs::read_to_string("./example/2024/day02").unwrap()).is_empty());
+        assert_eq!(Day2::validate("1 2 3\n\n4 x 6 y\n").len(), 3);
+    }
+
+    #[test]
+    fn test_day2_part2_example() {
+        let reports = Day2::parse(&fs::read_to_string("./example/2024/day02").unwrap()).unwrap();
+        let count = reports.iter().filter(|&r| Day2::is_safe_part2(r)).count() as u64;
+        assert_eq!(count, 4);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::aoc::{Answer, Solution};
+use crate::error::Result;
+use regex::Regex;
+
+/// Solution of [Day 3: Mull It Over](https://adventofcode.com/2024/day/3).
+pub struct Day3;
+
+impl Day3 {
+    fn sum_muls<'a>(sections: impl IntoIterator<Item = &'a str>) -> u64 {
+        let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+        sections
+            .into_iter()
+            .flat_map(|section| regex.captures_iter(section))
+            .map(|c| {
+                let x = c.get(1).unwrap().as_str().parse::<u64>().unwrap();
+                let y = c.get(2).unwrap().as_str().parse::<u64>().unwrap();
+
+                x * y
+            })
+            .sum()
+    }
+
+    /// The sections of memory between a `do()` and the next `don't()`.
+    fn enabled(data: &str) -> impl Iterator<Item = &str> {
+        data.split("do()")
+            .map(|section| section.split("don't()").next().unwrap_or_default())
+    }
+}
+
+impl Solution for Day3 {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part1(data: &&str) -> Result<Answer> {
+        Ok(Day3::sum_muls([*data]).into())
+    }
+
+    fn part2(data: &&str) -> Result<Answer> {
+        Ok(Day3::sum_muls(Day3::enabled(data)).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn day3_part1_count() {
+        let data: String = fs::read_to_string("./example/2024/day03").unwrap();
+        let regex = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
+
+        assert_eq!(regex.find_iter(&data).count(), 4);
+    }
+
+    #[test]
+    fn day3_part1_example() {
+        let data: String = fs::read_to_string("./example/2024/day03").unwrap();
+        assert_eq!(Day3::sum_muls([data.as_str()]), 161);
+    }
+
+    #[test]
+    fn day3_part2_count() {
+        let data: String = fs::read_to_string("./example/2024/day03").unwrap();
+        assert_eq!(data.match_indices("do()").count(), 1);
+        assert_eq!(data.match_indices("don't()").count(), 1);
+    }
+
+    #[test]
+    fn day3_part2_enabled() {
+        let data: String = fs::read_to_string("./example/2024/day03").unwrap();
+        assert_eq!(
+            Day3::enabled(&data).collect::<Vec<_>>(),
+            ["xmul(2,4)&mul[3,7]!^", "?mul(8,5))\n"]
+        );
+    }
+
+    #[test]
+    fn day3_part2_enabled2() {
+        let data = String::from("xdo()mul(2,4)&muldo()[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?don't()mul(8,5))");
+        assert_eq!(
+            Day3::enabled(&data).collect::<Vec<_>>(),
+            ["x", "mul(2,4)&mul", "[3,7]!^", "?"]
+        );
+    }
+
+    #[test]
+    fn day3_part2_example() {
+        let data = String::from(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        );
+        assert_eq!(Day3::sum_muls(Day3::enabled(&data)), 48);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    error::{AocError, Result},
+    utils::{dir::*, grid::Grid, parser},
+};
+
+use crate::aoc::{Answer, Solution};
+
+/// Solution of [Day 4: Ceres Search](https://adventofcode.com/2024/day/4).
+pub struct Day4;
+
+/// The letter grid.
+pub struct WordSearch<'a> {
+    grid: Grid<'a>,
+}
+
+impl WordSearch<'_> {
+    fn find_next(&self, pos: (isize, isize), dir: &Dir, find: u8) -> bool {
+        let checking = dir.from(pos);
+
+        if let Some(c) = self.grid.get(checking) {
+            if c != find {
+                return false;
+            }
+
+            match find {
+                b'M' => return self.find_next(checking, dir, b'A'),
+                b'A' => return self.find_next(checking, dir, b'S'),
+                b'S' => return true,
+                _ => panic!("Should not check for {}", find as char),
+            };
+        }
+
+        false
+    }
+
+    fn is_xmas(&self, pos: (isize, isize), dir: &Dir) -> bool {
+        if self.grid.get(pos) != Some(b'X') {
+            return false;
+        }
+
+        self.find_next(pos, dir, b'M')
+    }
+
+    fn count_xmas(&self) -> usize {
+        let xs = self.grid.positions(b'X');
+        xs.iter().fold(0, |acc, &pos| {
+            let current = (pos.0 as isize, pos.1 as isize);
+            acc + DIRS.iter().filter(|dir| self.is_xmas(current, dir)).count()
+        })
+    }
+
+    fn count_x_mas(&self) -> usize {
+        let ass = self.grid.positions(b'A');
+        ass.iter()
+            .filter(|&&pos| {
+                let current = (pos.0 as isize, pos.1 as isize);
+                let topleft = self.grid.get(Dir::TopLeft.from(current));
+                let bottomright = self.grid.get(Dir::BottomRight.from(current));
+
+                if !matches!(
+                    (topleft, bottomright),
+                    (Some(b'S'), Some(b'M')) | (Some(b'M'), Some(b'S'))
+                ) {
+                    return false;
+                }
+
+                let topright = self.grid.get(Dir::TopRight.from(current));
+                let bottomleft = self.grid.get(Dir::BottomLeft.from(current));
+
+                matches!(
+                    (topright, bottomleft),
+                    (Some(b'S'), Some(b'M')) | (Some(b'M'), Some(b'S'))
+                )
+            })
+            .count()
+    }
+}
+
+impl Solution for Day4 {
+    type Parsed<'a> = WordSearch<'a>;
+
+    fn parse(input: &str) -> Result<WordSearch<'_>> {
+        Ok(WordSearch {
+            grid: Grid::new(input),
+        })
+    }
+
+    fn validate(input: &str) -> Vec<AocError> {
+        parser::grid_problems(input, |c| "XMAS".contains(c))
+    }
+
+    fn part1(search: &WordSearch<'_>) -> Result<Answer> {
+        Ok(search.count_xmas().into())
+    }
+
+    fn part2(search: &WordSearch<'_>) -> Result<Answer> {
+        Ok(search.count_x_mas().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn day4_part1_is_xmas() {
+        let data = fs::read_to_string("./example/2024/day04").unwrap();
+        let day = Day4::parse(&data).unwrap();
+        assert!(day.is_xmas((0, 5), &Dir::Right));
+        assert!(day.is_xmas((0, 4), &Dir::BottomRight));
+    }
+
+    #[test]
+    fn day4_part1_is_not_xmas() {
+        let data = fs::read_to_string("./example/2024/day04").unwrap();
+        let day = Day4::parse(&data).unwrap();
+        assert!(!day.is_xmas((2, 2), &Dir::Left));
+        assert!(!day.is_xmas((7, 2), &Dir::BottomRight));
+    }
+
+    #[test]
+    fn day4_part1_example_count() {
+        let data = fs::read_to_string("./example/2024/day04").unwrap();
+        let day = Day4::parse(&data).unwrap();
+        assert_eq!(day.count_xmas(), 18);
+    }
+
+    #[test]
+    fn day4_part2_example_count() {
+        let data = fs::read_to_string("./example/2024/day04").unwrap();
+        let day = Day4::parse(&data).unwrap();
+        assert_eq!(day.count_x_mas(), 9);
+    }
+}
@@ -0,0 +1,368 @@
+use crate::aoc::{Answer, Solution};
+use crate::{
+    error::{AocError, Result},
+    utils::parser,
+};
+use std::collections::HashMap;
+
+#[derive(Default)]
+struct Rules(HashMap<usize, Vec<usize>>);
+
+impl Rules {
+    fn insert(&mut self, line_number: usize, rule: &str) -> Result<()> {
+        let numbers: Vec<usize> = rule
+            .split("|")
+            .map(|n| parser::number(line_number, rule, n))
+            .collect::<Result<_>>()?;
+        if numbers.len() != 2 {
+            return Err(AocError::parse(
+                line_number,
+                1,
+                rule,
+                "Expected a rule like 'X|Y'",
+            ));
+        }
+        let key = numbers[0];
+        if let Some(sucesors) = self.0.get_mut(&key) {
+            sucesors.push(numbers[1]);
+        } else {
+            self.0.insert(key, vec![numbers[1]]);
+        }
+
+        Ok(())
+    }
+}
+
+/// Solution of [Day 5: Print Queue](https://adventofcode.com/2024/day/5).
+pub struct Day5;
+
+/// The ordering rules and the updates to check.
+pub struct PrintQueue {
+    rules: Rules,
+    updates: Vec<Vec<usize>>,
+}
+
+impl PrintQueue {
+    fn parse(data: &str) -> Result<PrintQueue> {
+        let mut rules = Rules::default();
+        let mut updates = Vec::new();
+
+        for (i, line) in data.lines().enumerate() {
+            if line.contains("|") {
+                rules.insert(i + 1, line)?;
+            } else if line.contains(",") {
+                updates.push(
+                    line.split(",")
+                        .map(|n| parser::number(i + 1, line, n))
+                        .collect::<Result<_>>()?,
+                );
+            }
+        }
+
+        Ok(PrintQueue { rules, updates })
+    }
+
+    fn is_valid_update(&self, update: &[usize]) -> bool {
+        for (i, n) in update[..update.len() - 1].iter().enumerate() {
+            if let Some(v) = self.rules.0.get(n) {
+                for sucesor in update[i + 1..].iter() {
+                    if !v.contains(sucesor) {
+                        return false;
+                    }
+                }
+            } else {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn ordered(&self, update: &[usize]) -> Vec<usize> {
+        assert!(!update.is_empty());
+        let mut ordered = vec![update[0]];
+
+        for n in update[1..].iter() {
+            let mut inserted = false;
+            for i in 0..ordered.len() {
+                let o = ordered[i];
+                if let Some(v) = self.rules.0.get(&o) {
+                    if !v.contains(n) {
+                        ordered.insert(i, *n);
+                        inserted = true;
+                        break;
+                    }
+                } else {
+                    ordered.insert(i, *n);
+                    inserted = true;
+                    break;
+                }
+            }
+            if !inserted {
+                ordered.push(*n);
+            }
+        }
+
+        ordered
+    }
+}
+
+impl Solution for Day5 {
+    type Parsed<'a> = PrintQueue;
+
+    fn parse(input: &str) -> Result<PrintQueue> {
+        PrintQueue::parse(input)
+    }
+
+    fn validate(input: &str) -> Vec<AocError> {
+        let mut problems = Vec::new();
+        let mut rules = Rules::default();
+
+        for (i, line) in input.lines().enumerate() {
+            if line.contains("|") {
+                if let Err(err) = rules.insert(i + 1, line) {
+                    problems.push(err);
+                }
+            } else if line.contains(",") {
+                let pages: Vec<usize> = line
+                    .split(",")
+                    .filter_map(|n| {
+                        parser::number(i + 1, line, n)
+                            .map_err(|err| problems.push(err))
+                            .ok()
+                    })
+                    .collect();
+
+                if pages.len().is_multiple_of(2) {
+                    problems.push(AocError::parse(
+                        i + 1,
+                        1,
+                        line,
+                        "Expected an odd number of pages",
+                    ));
+                }
+                for (j, page) in pages.iter().enumerate() {
+                    for next in &pages[j + 1..] {
+                        let ordered = |a, b| rules.0.get(a).is_some_and(|v| v.contains(b));
+                        if !ordered(page, next) && !ordered(next, page) {
+                            problems.push(AocError::parse(
+                                i + 1,
+                                1,
+                                line,
+                                &format!("No rule orders pages {page} and {next}"),
+                            ));
+                        }
+                    }
+                }
+            } else if !line.trim().is_empty() {
+                problems.push(AocError::parse(
+                    i + 1,
+                    1,
+                    line,
+                    "Expected a rule 'X|Y' or an update 'A,B,C'",
+                ));
+            }
+        }
+
+        problems
+    }
+
+    fn part1(queue: &PrintQueue) -> Result<Answer> {
+        Ok(queue
+            .updates
+            .iter()
+            .filter(|u| queue.is_valid_update(u))
+            .map(|u| u[u.len() / 2])
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(queue: &PrintQueue) -> Result<Answer> {
+        Ok(queue
+            .updates
+            .iter()
+            .filter(|u| !queue.is_valid_update(u))
+            .map(|u| queue.ordered(u))
+            .map(|u| u[u.len() / 2])
+            .sum::<usize>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::prop::{self, Rng};
+    use std::fs;
+
+    /// A total order of pages and updates as positions into it.
+    type Queue = (Vec<usize>, Vec<Vec<usize>>);
+
+    /// Pages in order, updates and rules as `(before, after)`.
+    type Printing = (Vec<usize>, Vec<Vec<usize>>, Vec<(usize, usize)>);
+
+    fn random_queue(rng: &mut Rng) -> Queue {
+        let mut order: Vec<usize> = (10..100).collect();
+        rng.shuffle(&mut order);
+        order.truncate(rng.range(2, 15));
+
+        let updates = (0..rng.range(1, 6))
+            .map(|_| {
+                let mut update: Vec<usize> = (0..order.len()).collect();
+                rng.shuffle(&mut update);
+                update.truncate(rng.range(2, order.len()));
+                update
+            })
+            .collect();
+
+        (order, updates)
+    }
+
+    /// Pages of each update with at least two, and the rules between pages that
+    /// share an update.
+    fn normalize((order, updates): &Queue) -> Printing {
+        let mut pages: Vec<usize> = Vec::new();
+        for page in order {
+            if !pages.contains(page) {
+                pages.push(*page);
+            }
+        }
+
+        let rank = |page: &usize| pages.iter().position(|p| p == page);
+        let mut rules = Vec::new();
+        let mut normalized = Vec::new();
+        for update in updates {
+            let update: Vec<usize> = update
+                .iter()
+                .filter_map(|&i| pages.get(i).copied())
+                .collect();
+            if update.len() < 2 || (1..update.len()).any(|i| update[..i].contains(&update[i])) {
+                continue;
+            }
+
+            for a in &update {
+                for b in &update {
+                    if rank(a) < rank(b) && !rules.contains(&(*a, *b)) {
+                        rules.push((*a, *b));
+                    }
+                }
+            }
+            normalized.push(update);
+        }
+
+        (pages, normalized, rules)
+    }
+
+    fn matches_brute_force(queue: &Queue) -> std::result::Result<(), String> {
+        let (pages, updates, rules) = normalize(queue);
+        let rank = |page: &usize| pages.iter().position(|p| p == page);
+
+        let mut input: String = rules.iter().map(|(a, b)| format!("{a}|{b}\n")).collect();
+        input.push('\n');
+        for update in &updates {
+            let pages: Vec<String> = update.iter().map(|p| p.to_string()).collect();
+            input.push_str(&pages.join(","));
+            input.push('\n');
+        }
+
+        let (mut correct, mut fixed) = (0, 0);
+        for update in &updates {
+            let mut sorted = update.clone();
+            sorted.sort_by_key(rank);
+            if sorted == *update {
+                correct += update[update.len() / 2];
+            } else {
+                fixed += sorted[sorted.len() / 2];
+            }
+        }
+
+        let queue = Day5::parse(&input).map_err(|err| err.to_string())?;
+        let answers = (Day5::part1(&queue).unwrap(), Day5::part2(&queue).unwrap());
+
+        if answers != (correct.into(), fixed.into()) {
+            return Err(format!(
+                "expected ({correct}, {fixed}), got ({}, {}) for\n{input}",
+                answers.0, answers.1
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn day5_matches_brute_force() {
+        prop::check("day5", random_queue, matches_brute_force);
+    }
+
+    #[test]
+    fn day5_part1_data() {
+        let day = Day5::parse(
+            "
+47|53
+97|13
+97|61
+
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+",
+        )
+        .unwrap();
+        assert_eq!(day.rules.0.len(), 2);
+        assert_eq!(day.updates.len(), 3);
+        assert_eq!(day.rules.0.get(&97), Some(vec![13, 61]).as_ref());
+        assert_eq!(day.rules.0.get(&47), Some(vec![53]).as_ref());
+        assert_eq!(day.rules.0.get(&53), None);
+        assert_eq!(day.updates[1], vec![61, 13, 29]);
+    }
+
+    #[test]
+    fn day5_part1_valid_update() {
+        let day = Day5::parse(&fs::read_to_string("./example/2024/day05").unwrap()).unwrap();
+        let r: Vec<bool> = day.updates.iter().map(|u| day.is_valid_update(u)).collect();
+        assert_eq!(r, vec![true, true, true, false, false, false]);
+    }
+
+    #[test]
+    fn day5_part1_example() {
+        let day = Day5::parse(&fs::read_to_string("./example/2024/day05").unwrap()).unwrap();
+        assert_eq!(Day5::part1(&day).unwrap(), Answer::from(143usize));
+    }
+
+    #[test]
+    fn day5_part2_ordered() {
+        let day = Day5::parse(&fs::read_to_string("./example/2024/day05").unwrap()).unwrap();
+        assert_eq!(day.ordered(&day.updates[3]), vec![97, 75, 47, 61, 53]);
+        assert_eq!(day.ordered(&day.updates[4]), vec![61, 29, 13]);
+        assert_eq!(day.ordered(&day.updates[5]), vec![97, 75, 47, 29, 13]);
+    }
+
+    #[test]
+    fn day5_part2_example() {
+        let day = Day5::parse(&fs::read_to_string("./example/2024/day05").unwrap()).unwrap();
+        assert_eq!(Day5::part2(&day).unwrap(), Answer::from(123usize));
+    }
+
+    #[test]
+    fn day5_validate() {
+        assert!(Day5::validate(&fs::read_to_string("./example/2024/day05").unwrap()).is_empty());
+
+        let problems = Day5::validate("1|2\n2|3\n1|3\n\n1,2\n1,x,3\n7\n1,4,3\n");
+        let lines: Vec<usize> = problems
+            .iter()
+            .map(|err| match err {
+                AocError::Parse { line, .. } => *line,
+                _ => panic!("Expected a parse error"),
+            })
+            .collect();
+        assert_eq!(lines, [5, 6, 6, 7, 8, 8]);
+    }
+
+    #[test]
+    fn day5_parse_error() {
+        match Day5::parse("47|53\n97|\n") {
+            Err(AocError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 4)),
+            _ => panic!("Expected a parse error"),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use crate::aoc::{Answer, Solution};
+use crate::{
+    error::{AocError, Result},
+    utils::{
+        dir::Dir,
+        grid::Grid,
+        matrix::{Matrix, MatrixTrait},
+        parser,
+    },
+};
+use std::collections::HashMap;
+
+type Point = (usize, usize);
+
+/// Solution of [Day 6: Guard Gallivant](https://adventofcode.com/2024/day/6).
+pub struct Day6;
+
+/// The lab map and where the guard starts.
+pub struct Lab<'a> {
+    grid: Grid<'a>,
+    start: Point,
+}
+
+struct Patrol {
+    table: Matrix<u8>,
+    dirs: HashMap<Point, Vec<Dir>>,
+    start: Point,
+    current: Point,
+    dir: Dir,
+}
+
+impl Patrol {
+    fn new(lab: &Lab<'_>) -> Patrol {
+        Patrol {
+            table: lab.grid.to_matrix(),
+            dirs: HashMap::new(),
+            start: lab.start,
+            current: lab.start,
+            dir: Dir::Top,
+        }
+    }
+
+    fn is_wall(&mut self, p: (isize, isize)) -> bool {
+        self.table.from_pos(p) == Some(&b'#')
+    }
+
+    fn update_dir(&mut self) {
+        match self.dir {
+            Dir::Top => self.dir = Dir::Right,
+            Dir::Right => self.dir = Dir::Bottom,
+            Dir::Left => self.dir = Dir::Top,
+            Dir::Bottom => self.dir = Dir::Left,
+            _ => panic!("Not a valid dir {:?}", self.dir),
+        }
+    }
+
+    fn get_next(&mut self) -> Point {
+        let current = (self.current.0 as isize, self.current.1 as isize);
+        let mut next = self.dir.from(current);
+
+        while self.is_wall(next) {
+            self.update_dir();
+            next = self.dir.from(current);
+        }
+
+        (next.0 as usize, next.1 as usize)
+    }
+
+    fn is_edge_current(&self) -> bool {
+        self.current.0 == 0
+            || self.current.0 == self.table.len() - 1
+            || self.current.1 == 0
+            || self.current.1 == self.table[0].len() - 1
+    }
+
+    fn reset(&mut self) {
+        self.dir = Dir::Top;
+        self.current = self.start;
+        self.dirs = HashMap::new();
+    }
+
+    fn patrol(&mut self) -> Result<Vec<Point>> {
+        self.reset();
+        let max_steps = self.table.len() * self.table[0].len() * 4;
+        let mut steps = 0;
+
+        while !self.is_edge_current() {
+            self.table[self.current.0][self.current.1] = b'X';
+            self.current = self.get_next();
+
+            steps += 1;
+            if steps > max_steps {
+                return Err(AocError::Unsolvable(String::from(
+                    "The guard never leaves the map",
+                )));
+            }
+        }
+
+        self.table[self.current.0][self.current.1] = b'X';
+
+        Ok(self.table.positions(&b'X'))
+    }
+
+    fn is_loop(&mut self, p: Point) -> bool {
+        if p == self.start {
+            return false;
+        }
+
+        self.table[p.0][p.1] = b'#';
+
+        while !self.is_edge_current() {
+            self.current = self.get_next();
+
+            let directions = self.dirs.entry(self.current).or_default();
+
+            if directions.contains(&self.dir) {
+                self.table[p.0][p.1] = b'X';
+                return true;
+            } else {
+                directions.push(self.dir);
+            }
+        }
+
+        if let Some(directions) = self.dirs.get(&p) {
+            if directions.contains(&self.dir) {
+                self.table[p.0][p.1] = b'X';
+                return true;
+            }
+        }
+
+        self.table[p.0][p.1] = b'X';
+        false
+    }
+}
+
+impl Solution for Day6 {
+    type Parsed<'a> = Lab<'a>;
+
+    fn parse(input: &str) -> Result<Lab<'_>> {
+        let grid = Grid::new(input);
+        let start = *grid
+            .positions(b'^')
+            .first()
+            .ok_or_else(|| AocError::InvalidInput(String::from("No guard '^' in the map")))?;
+
+        Ok(Lab { grid, start })
+    }
+
+    fn validate(input: &str) -> Vec<AocError> {
+        let mut problems = parser::grid_problems(input, |c| ".#^".contains(c));
+        let guards = Grid::new(input).positions(b'^');
+
+        match guards.as_slice() {
+            [] => problems.push(AocError::InvalidInput(String::from(
+                "No guard '^' in the map",
+            ))),
+            [_] => {}
+            [_, rest @ ..] => {
+                for &(i, j) in rest {
+                    problems.push(AocError::parse(
+                        i + 1,
+                        j + 1,
+                        "^",
+                        "Expected a single guard",
+                    ));
+                }
+            }
+        }
+
+        problems
+    }
+
+    fn part1(lab: &Lab<'_>) -> Result<Answer> {
+        Ok(Patrol::new(lab).patrol()?.len().into())
+    }
+
+    fn part2(lab: &Lab<'_>) -> Result<Answer> {
+        let mut patrol = Patrol::new(lab);
+        let visited = patrol.patrol()?;
+        Ok(visited
+            .iter()
+            .filter(|&p| {
+                patrol.reset();
+                patrol.is_loop(*p)
+            })
+            .count()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    impl Patrol {
+        fn example() -> Patrol {
+            Patrol::new(&Day6::parse(&fs::read_to_string("./example/2024/day06").unwrap()).unwrap())
+        }
+    }
+
+    #[test]
+    fn day6_part1_parse() {
+        let day = Patrol::example();
+        assert_eq!(day.table.len(), 10);
+        assert_eq!(day.table[0].len(), 10);
+        assert_eq!(day.current, (6, 4));
+        assert_eq!(day.dir, Dir::Top);
+    }
+
+    #[test]
+    fn day6_part1_example() {
+        let mut day = Patrol::example();
+        assert_eq!(day.patrol().unwrap().len(), 41)
+    }
+
+    #[test]
+    fn day6_part2_example() {
+        let mut day = Patrol::example();
+        day.patrol().unwrap();
+        day.reset();
+        assert!(day.is_loop((6, 3)));
+        day.reset();
+        assert!(day.is_loop((7, 6)));
+        day.reset();
+        assert!(day.is_loop((7, 7)));
+        day.reset();
+        assert!(day.is_loop((8, 1)));
+        day.reset();
+        assert!(day.is_loop((8, 3)));
+        day.reset();
+        assert!(day.is_loop((9, 7)));
+        day.reset();
+        assert!(!day.is_loop((1, 7)));
+        let visited = day.table.positions(&b'X');
+        let total = visited
+            .iter()
+            .filter(|&p| {
+                day.reset();
+                day.is_loop(*p)
+            })
+            .count();
+        assert_eq!(total, 6);
+    }
+
+    #[test]
+    fn day6_validate() {
+        assert!(Day6::validate(&fs::read_to_string("./example/2024/day06").unwrap()).is_empty());
+        assert_eq!(Day6::validate("..#\n.^.\n^.x\n").len(), 2);
+        assert!(!Day6::validate("..#\n.^\n...\n").is_empty());
+        assert!(matches!(
+            Day6::validate("...\n...\n").as_slice(),
+            [AocError::InvalidInput(_)]
+        ));
+    }
+}
@@ -1,30 +1,39 @@
-use super::Solution;
-use crate::utils::{
-    matrix::{Matrix, MatrixTrait},
-    parser,
-};
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
+use crate::aoc::{Answer, Solution};
+use crate::{
+    error::{AocError, Result},
+    utils::{grid::Grid, parser},
 };
+use std::collections::{HashMap, HashSet};
 
 type Point = (usize, usize);
 
-#[derive(Default)]
-pub struct Day8 {
-    table: Matrix<char>,
-    antennas: HashMap<char, Vec<Point>>,
+/// Solution of [Day 8: Resonant Collinearity](https://adventofcode.com/2024/day/8).
+pub struct Day8;
+
+/// The city map and the positions of each antenna frequency.
+pub struct Antennas<'a> {
+    table: Grid<'a>,
+    antennas: HashMap<u8, Vec<Point>>,
 }
 
-impl Day8 {
+impl<'a> Antennas<'a> {
+    fn new(table: Grid<'a>) -> Antennas<'a> {
+        let mut antennas = Antennas {
+            table,
+            antennas: HashMap::new(),
+        };
+        antennas.get_antennas();
+
+        antennas
+    }
+
     fn get_antennas(&mut self) {
-        for (i, _) in self.table.iter().enumerate() {
-            for (j, _) in self.table[i].iter().enumerate() {
-                let current = self.table[i][j];
-                if current == '.' {
+        for (i, row) in self.table.rows().enumerate() {
+            for (j, &current) in row.iter().enumerate() {
+                if current == b'.' {
                     continue;
                 }
-                let positions = self.antennas.entry(current).or_insert_with(Vec::new);
+                let positions = self.antennas.entry(current).or_default();
                 positions.push((i, j));
             }
         }
@@ -96,57 +105,60 @@ impl Day8 {
 }
 
 impl Solution for Day8 {
-    fn parse_input(&mut self) {
-        self.table = parser::to_chars_matrix(&fs::read_to_string("./input/day8").unwrap());
-        self.get_antennas()
+    type Parsed<'a> = Antennas<'a>;
+
+    fn parse(input: &str) -> Result<Antennas<'_>> {
+        Ok(Antennas::new(Grid::new(input)))
+    }
+
+    fn validate(input: &str) -> Vec<AocError> {
+        parser::grid_problems(input, |c| c == '.' || c.is_ascii_alphanumeric())
     }
 
-    fn part1(&mut self) -> u64 {
-        self.get_antinodes() as u64
+    fn part1(antennas: &Antennas<'_>) -> Result<Answer> {
+        Ok(antennas.get_antinodes().into())
     }
 
-    fn part2(&mut self) -> u64 {
-        self.get_antinodes2() as u64
+    fn part2(antennas: &Antennas<'_>) -> Result<Answer> {
+        Ok(antennas.get_antinodes2().into())
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
 
-    impl Day8 {
-        fn new() -> Day8 {
-            let mut day = Day8::default();
-            day.table = parser::to_chars_matrix(&fs::read_to_string("./example/day8").unwrap());
-            day.get_antennas();
-
-            day
-        }
+    fn example() -> String {
+        fs::read_to_string("./example/2024/day08").unwrap()
     }
 
     #[test]
     fn day8_part1_parse() {
-        let day = Day8::new();
+        let data = example();
+        let day = Day8::parse(&data).unwrap();
         assert_eq!(day.antennas.len(), 2);
         assert!(day
             .antennas
-            .get(&'A')
+            .get(&b'A')
             .is_some_and(|p| *p == vec![(5, 6), (8, 8), (9, 9)]));
         assert!(day
             .antennas
-            .get(&'0')
+            .get(&b'0')
             .is_some_and(|p| *p == vec![(1, 8), (2, 5), (3, 7), (4, 4)]));
     }
 
     #[test]
     fn day8_part1_example() {
-        let day = Day8::new();
+        let data = example();
+        let day = Day8::parse(&data).unwrap();
         assert_eq!(day.get_antinodes(), 14);
     }
 
     #[test]
     fn day8_part2_example() {
-        let day = Day8::new();
+        let data = example();
+        let day = Day8::parse(&data).unwrap();
         assert_eq!(day.get_antinodes2(), 34);
     }
 }
@@ -0,0 +1,290 @@
+use crate::aoc::{Answer, Solution};
+use crate::error::{AocError, Result};
+
+/// Solution of [Day 9: Disk Fragmenter](https://adventofcode.com/2024/day/9).
+pub struct Day9;
+
+/// The file and free space lengths of the disk map.
+#[derive(Default, Clone)]
+pub struct Disk {
+    files: Vec<usize>,
+    free: Vec<usize>,
+    free_ocupied: Vec<usize>,
+}
+
+impl Disk {
+    fn new(data: &str) -> Disk {
+        let mut disk = Disk::default();
+        for c in data.chars().step_by(2) {
+            if let Some(n) = c.to_digit(10) {
+                disk.files.push(n as usize);
+            }
+        }
+        for c in data.chars().skip(1).step_by(2) {
+            if let Some(n) = c.to_digit(10) {
+                disk.free.push(n as usize);
+            }
+        }
+
+        disk
+    }
+
+    fn move_blocks(&mut self) {
+        let mut free_len = self.free.len();
+        let mut free = 0;
+        let mut file = self.files.len() - 1;
+        let mut cuantity = self.files[file];
+        let mut breaking = false;
+        while free < free_len {
+            let f = self.free[free];
+            for _ in 0..f {
+                if cuantity == 0 {
+                    self.files.pop();
+                    self.free.pop();
+                    if free == free_len - 1 {
+                        breaking = true;
+                        break;
+                    }
+                    file -= 1;
+                    free_len -= 1;
+                    cuantity = self.files[file];
+                }
+                self.free_ocupied.push(file);
+                cuantity -= 1;
+            }
+            if breaking {
+                break;
+            }
+            if cuantity == 0 {
+                self.files.pop();
+                self.free.pop();
+                file -= 1;
+                free_len -= 1;
+                cuantity = self.files[file];
+            }
+
+            free += 1;
+        }
+        if !breaking {
+            self.files[file] = cuantity;
+        }
+    }
+
+    fn get_checksum(&self) -> usize {
+        let mut file = 0;
+        let mut free = 0;
+        let mut ocupied = 0;
+        let mut file_turn = true;
+        let mut index = 0;
+        let mut sum = 0;
+
+        loop {
+            if file == self.files.len() {
+                file_turn = false;
+            }
+
+            if file_turn {
+                for _ in 0..self.files[file] {
+                    sum += file * index;
+                    index += 1;
+                }
+                file += 1;
+                file_turn = false;
+            } else {
+                if free < self.free.len() {
+                    for _ in 0..self.free[free] {
+                        sum += self.free_ocupied[ocupied] * index;
+                        index += 1;
+                        ocupied += 1;
+                    }
+                    free += 1;
+                    file_turn = file < self.files.len();
+                } else {
+                    if ocupied < self.free_ocupied.len() {
+                        for i in &self.free_ocupied[ocupied..] {
+                            sum += *i * index;
+                            index += 1;
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+
+        sum
+    }
+
+    fn compact_files(&mut self) -> usize {
+        self.move_blocks();
+        self.get_checksum()
+    }
+}
+
+impl Solution for Day9 {
+    type Parsed<'a> = Disk;
+
+    fn parse(input: &str) -> Result<Disk> {
+        let disk = Disk::new(input);
+
+        if disk.files.is_empty() {
+            return Err(AocError::InvalidInput(String::from("Empty disk map")));
+        }
+
+        Ok(disk)
+    }
+
+    fn validate(input: &str) -> Vec<AocError> {
+        let mut problems = Vec::new();
+        let mut lines = input.lines().enumerate();
+
+        match lines.next() {
+            None => problems.push(AocError::InvalidInput(String::from("Empty disk map"))),
+            Some((_, line)) => {
+                for (j, c) in line.chars().enumerate() {
+                    if !c.is_ascii_digit() {
+                        problems.push(AocError::parse(
+                            1,
+                            j + 1,
+                            &c.to_string(),
+                            "Expected a digit",
+                        ));
+                    } else if j % 2 == 0 && c == '0' {
+                        problems.push(AocError::parse(
+                            1,
+                            j + 1,
+                            "0",
+                            "Expected a file of 1 or more blocks",
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (i, line) in lines.filter(|(_, line)| !line.trim().is_empty()) {
+            problems.push(AocError::parse(i + 1, 1, line, "Expected a single line"));
+        }
+
+        problems
+    }
+
+    fn part1(disk: &Disk) -> Result<Answer> {
+        Ok(disk.clone().compact_files().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::prop::{self, Rng};
+    use std::fs;
+
+    /// Files as (length - 1, free space after it), so shrinking keeps files non-empty.
+    type DiskMap = Vec<(usize, usize)>;
+
+    fn random_disk_map(rng: &mut Rng) -> DiskMap {
+        (0..rng.range(1, 12))
+            .map(|_| (rng.range(0, 8), rng.range(0, 9)))
+            .collect()
+    }
+
+    fn render(map: &DiskMap) -> String {
+        let mut digits = String::new();
+        for (i, (file, free)) in map.iter().enumerate() {
+            digits.push_str(&(file + 1).to_string());
+            if i + 1 < map.len() {
+                digits.push_str(&free.to_string());
+            }
+        }
+        digits
+    }
+
+    fn brute_force_checksum(map: &DiskMap) -> usize {
+        let mut blocks: Vec<Option<usize>> = Vec::new();
+        for (id, (file, free)) in map.iter().enumerate() {
+            blocks.extend(std::iter::repeat_n(Some(id), file + 1));
+            blocks.extend(std::iter::repeat_n(None, *free));
+        }
+
+        let (mut left, mut right) = (0, blocks.len());
+        loop {
+            while left < right && blocks[left].is_some() {
+                left += 1;
+            }
+            while left < right && blocks[right - 1].is_none() {
+                right -= 1;
+            }
+            if left + 1 >= right {
+                break;
+            }
+            blocks.swap(left, right - 1);
+        }
+
+        blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(i, id)| id.map(|id| i * id))
+            .sum()
+    }
+
+    fn matches_brute_force(map: &DiskMap) -> std::result::Result<(), String> {
+        if map.is_empty() {
+            return Ok(());
+        }
+
+        let input = render(map);
+        let expected = brute_force_checksum(map);
+        let got = Disk::new(&input).compact_files();
+
+        if got != expected {
+            return Err(format!("expected {expected}, got {got} for {input}"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn day9_matches_brute_force() {
+        assert_eq!(brute_force_checksum(&vec![(0, 2), (2, 4), (4, 0)]), 60);
+        prop::check("day9", random_disk_map, matches_brute_force);
+    }
+
+    #[test]
+    fn day9_part1_moveblocks() {
+        let mut day = Disk::new(&fs::read_to_string("./example/2024/day09").unwrap());
+        day.move_blocks();
+        assert_eq!(day.free_ocupied, vec![9, 9, 8, 8, 8, 8, 7, 7, 7, 6, 6, 6]);
+        assert_eq!(day.files, vec![2, 3, 1, 3, 2, 4, 1]);
+        assert_eq!(day.free, vec![3, 3, 3, 1, 1, 1]);
+    }
+
+    #[test]
+    fn day9_part1_moveblocks2() {
+        let mut day = Disk::new("12345");
+        day.move_blocks();
+        assert_eq!(day.free_ocupied, vec![2, 2, 2, 2, 2]);
+        assert_eq!(day.files, vec![1, 3]);
+        assert_eq!(day.free, vec![2]);
+    }
+
+    #[test]
+    fn day9_part1_sum() {
+        let mut day = Disk::new("12345");
+        let sum = day.compact_files();
+        assert_eq!(sum, 60);
+    }
+
+    #[test]
+    fn day9_part1_example() {
+        let mut day = Disk::new(&fs::read_to_string("./example/2024/day09").unwrap());
+        let sum = day.compact_files();
+        assert_eq!(sum, 1928);
+    }
+
+    #[test]
+    fn day9_validate() {
+        assert!(Day9::validate(&fs::read_to_string("./example/2024/day09").unwrap()).is_empty());
+        assert_eq!(Day9::validate("12x45\n").len(), 1);
+        assert_eq!(Day9::validate("1203\n45\n").len(), 2);
+        assert_eq!(Day9::validate("").len(), 1);
+    }
+}
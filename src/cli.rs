@@ -0,0 +1,395 @@
+use aoc_2024::aoc::{self, BenchOptions, Format, Input, RunOptions};
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: {program} [command] [year] <days> [options]
+       {program} submit [year] <day> <part>
+       {program} watch [year] <day> [--example]
+       {program} new [year] <day> [--title <title>]
+
+\t- year: 2015 or later, the latest year with solutions by default
+\t- days: number between 1 and 25, a range (1-5), a list (1,3,7) or 'all'
+
+Commands:
+\trun               solve the days (default)
+\tverify            compare the answers with ./answers/YYYY/dayNN.toml
+\tcheck             validate the inputs and list every problem without solving
+\tbench             time many runs, report min/median/mean/stddev and append to ./answers/YYYY/bench.history
+\tcompare           bench again and compare the medians with the last recorded bench
+\tcrosscheck        run every variant of each part and report disagreements and relative timings
+\tfetch             download the inputs to ./input/YYYY/dayNN (needs session.id or AOC_SESSION)
+\tsubmit            solve a part on ./input/YYYY/dayNN and submit the answer
+\twatch             rebuild and rerun a day when its source or inputs change
+\tnew               create src/aoc/yearYYYY/dayNN.rs and ./example/YYYY/dayNN and register the day
+
+Options:
+\t--input <file>    read the puzzle input from <file> ('-' for stdin)
+\t--example         read the input from ./example/YYYY/dayNN
+\t-                 read the input from stdin
+\t--format <fmt>    output format for run and bench: text, json or csv
+\t--jobs N          (run, verify) solve up to N days in parallel
+\t--timeout <time>  (run, verify, watch) give up on a part after <time>, e.g. 10s
+\t--part N          (run, watch) solve only part N, 1 or 2
+\t--mem             (run) count allocations, bytes and peak memory per part
+\t--variant <name>  (run, verify, watch) solve the parts that have it with variant <name>
+\t--save            (verify) store the answers of UNKNOWN parts
+\t--base-url <url>  (fetch, submit) server to download from (default AOC_BASE_URL or adventofcode.com)
+\t--title <title>   (new) puzzle title for the registry (default 'Day N')
+\t--iterations N    (bench, compare) maximum number of runs (default 1000)
+\t--budget <time>   (bench, compare) time budget per day, e.g. 500ms, 3s (default 3s)
+\t--threshold N     (compare) fail when a median is more than N% slower (default 10)";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    #[default]
+    Run,
+    Verify,
+    Check,
+    Bench,
+    Compare,
+    Crosscheck,
+    Fetch,
+    Submit,
+    Watch,
+    New,
+}
+
+pub struct Args {
+    pub command: Command,
+    pub year: usize,
+    pub days: Vec<usize>,
+    pub part: Option<usize>,
+    pub input: Input,
+    pub format: Format,
+    pub run: RunOptions,
+    pub save: bool,
+    pub bench: BenchOptions,
+    pub threshold: Option<f64>,
+    pub base_url: Option<String>,
+    pub title: Option<String>,
+}
+
+pub fn parse(args: &[String]) -> Result<Args, String> {
+    let mut command = Command::default();
+    let mut year = None;
+    let mut days = None;
+    let mut part = None;
+    let mut input = Input::Puzzle;
+    let mut format = Format::default();
+    let mut run = RunOptions::default();
+    let mut save = false;
+    let mut bench = BenchOptions::default();
+    let mut threshold = None;
+    let mut base_url = None;
+    let mut title = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                input = match args.next().map(String::as_str) {
+                    Some("-") => Input::Stdin,
+                    Some(path) => Input::File(path.into()),
+                    None => return Err(String::from("--input requires a file")),
+                }
+            }
+            "--example" => input = Input::Example,
+            "--format" => format = value(args.next(), arg)?.parse()?,
+            "--jobs" => {
+                run.jobs = match value(args.next(), arg)?.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(String::from("--jobs requires a positive number")),
+                }
+            }
+            "--timeout" => run.timeout = Some(parse_duration(value(args.next(), arg)?)?),
+            "--part" => {
+                run.part = match value(args.next(), arg)? {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(String::from("--part requires 1 or 2")),
+                }
+            }
+            "--mem" => run.memory = true,
+            "--variant" => run.variant = Some(value(args.next(), arg)?.to_string()),
+            "--save" => save = true,
+            "--base-url" => base_url = Some(value(args.next(), arg)?.to_string()),
+            "--title" => title = Some(value(args.next(), arg)?.to_string()),
+            "--iterations" => {
+                bench.iterations = value(args.next(), arg)?
+                    .parse()
+                    .map_err(|_| String::from("--iterations requires a number"))?
+            }
+            "--budget" => bench.budget = parse_duration(value(args.next(), arg)?)?,
+            "--threshold" => {
+                threshold = match value(args.next(), arg)?.trim_end_matches('%').parse() {
+                    Ok(n) if n >= 0.0 => Some(n),
+                    _ => return Err(String::from("--threshold requires a percentage")),
+                }
+            }
+            "-" => input = Input::Stdin,
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{arg}'")),
+            "run" if days.is_none() => command = Command::Run,
+            "verify" if days.is_none() => command = Command::Verify,
+            "check" if days.is_none() => command = Command::Check,
+            "bench" if days.is_none() => command = Command::Bench,
+            "compare" if days.is_none() => command = Command::Compare,
+            "crosscheck" if days.is_none() => command = Command::Crosscheck,
+            "fetch" if days.is_none() => command = Command::Fetch,
+            "submit" if days.is_none() => command = Command::Submit,
+            "watch" if days.is_none() => command = Command::Watch,
+            "new" if days.is_none() => command = Command::New,
+            _ if days.is_none() && year.is_none() && is_year(arg) => year = arg.parse().ok(),
+            _ if days.is_none() => {
+                days = Some(aoc::select_days(
+                    year.unwrap_or_else(aoc::latest_year),
+                    arg,
+                )?)
+            }
+            "1" | "2" if command == Command::Submit && part.is_none() => part = arg.parse().ok(),
+            _ => return Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    let year = year.unwrap_or_else(aoc::latest_year);
+    let days = days.ok_or_else(|| String::from("missing days"))?;
+
+    if days.is_empty() {
+        return Err(format!("no days implemented in {year}"));
+    }
+
+    if matches!(input, Input::File(_) | Input::Stdin) && days.len() > 1 {
+        return Err(String::from("a single input can only be used with one day"));
+    }
+
+    if command == Command::Submit {
+        if days.len() != 1 || part.is_none() {
+            return Err(String::from("submit requires one day and a part (1 or 2)"));
+        }
+        if input != Input::Puzzle {
+            return Err(String::from("submit always uses ./input/YYYY/dayNN"));
+        }
+    }
+
+    if command == Command::New && days.len() != 1 {
+        return Err(String::from("new requires a single day"));
+    }
+
+    if command == Command::Watch {
+        if days.len() != 1 {
+            return Err(String::from("watch requires a single day"));
+        }
+        if !matches!(input, Input::Puzzle | Input::Example) {
+            return Err(String::from(
+                "watch only reads ./input/YYYY/dayNN or ./example/YYYY/dayNN",
+            ));
+        }
+    }
+
+    if title.is_some() && command != Command::New {
+        return Err(String::from("--title can only be used with new"));
+    }
+
+    if run.part.is_some() && !matches!(command, Command::Run | Command::Watch) {
+        return Err(String::from("--part can only be used with run and watch"));
+    }
+
+    if run.memory && command != Command::Run {
+        return Err(String::from("--mem can only be used with run"));
+    }
+
+    if run.variant.is_some() && !matches!(command, Command::Run | Command::Verify | Command::Watch)
+    {
+        return Err(String::from(
+            "--variant can only be used with run, verify and watch",
+        ));
+    }
+
+    if save && command != Command::Verify {
+        return Err(String::from("--save can only be used with verify"));
+    }
+
+    if format != Format::Text
+        && matches!(
+            command,
+            Command::Verify | Command::Check | Command::Compare | Command::Crosscheck
+        )
+    {
+        return Err(String::from("--format can only be used with run and bench"));
+    }
+
+    if matches!(
+        command,
+        Command::Check | Command::Bench | Command::Compare | Command::Crosscheck
+    ) && (run.jobs > 1 || run.timeout.is_some())
+    {
+        return Err(String::from(
+            "--jobs and --timeout cannot be used with check, bench, compare or crosscheck",
+        ));
+    }
+
+    if threshold.is_some() && command != Command::Compare {
+        return Err(String::from("--threshold can only be used with compare"));
+    }
+
+    Ok(Args {
+        command,
+        year,
+        days,
+        part,
+        input,
+        format,
+        run,
+        save,
+        bench,
+        threshold,
+        base_url,
+        title,
+    })
+}
+
+fn is_year(arg: &str) -> bool {
+    arg.len() == 4 && arg.parse::<usize>().is_ok_and(|year| year >= 2015)
+}
+
+fn value<'a>(value: Option<&'a String>, option: &str) -> Result<&'a str, String> {
+    value
+        .map(String::as_str)
+        .ok_or_else(|| format!("{option} requires a value"))
+}
+
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{text}'"))?;
+
+    let seconds = match unit {
+        "ns" => number / 1e9,
+        "us" | "µs" => number / 1e6,
+        "ms" => number / 1e3,
+        "s" | "" => number,
+        "m" => number * 60.0,
+        _ => return Err(format!("invalid duration unit in '{text}'")),
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid duration '{text}'"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn cli_input_options() {
+        assert_eq!(parse(&args("3")).unwrap().input, Input::Puzzle);
+        assert_eq!(parse(&args("3 --example")).unwrap().input, Input::Example);
+        assert_eq!(parse(&args("3 -")).unwrap().input, Input::Stdin);
+        assert_eq!(parse(&args("--input -  3")).unwrap().input, Input::Stdin);
+        assert_eq!(
+            parse(&args("3 --input other")).unwrap().input,
+            Input::File("other".into())
+        );
+    }
+
+    #[test]
+    fn cli_errors() {
+        assert!(parse(&args("")).is_err());
+        assert!(parse(&args("3 --input")).is_err());
+        assert!(parse(&args("3 --bogus")).is_err());
+        assert!(parse(&args("1-3 --input other")).is_err());
+        assert!(parse(&args("1-3 --example")).is_ok());
+        assert!(parse(&args("3 --save")).is_err());
+        assert!(parse(&args("3 --format xml")).is_err());
+        assert!(parse(&args("verify 3 --format json")).is_err());
+        assert!(parse(&args("all --jobs 0")).is_err());
+        assert!(parse(&args("bench all --jobs 2")).is_err());
+        assert!(parse(&args("bench all --timeout 1s")).is_err());
+        assert!(parse(&args("submit 7")).is_err());
+        assert!(parse(&args("submit 7 3")).is_err());
+        assert!(parse(&args("submit 1-2 1")).is_err());
+        assert!(parse(&args("submit 7 1 --example")).is_err());
+        assert!(parse(&args("bench 7 --variant string")).is_err());
+        assert!(parse(&args("check 7 --format csv")).is_err());
+        assert!(parse(&args("6 --part 3")).is_err());
+        assert!(parse(&args("verify 6 --part 1")).is_err());
+        assert!(parse(&args("crosscheck 7 --timeout 1s")).is_err());
+        assert!(parse(&args("7 --variant")).is_err());
+    }
+
+    #[test]
+    fn cli_durations() {
+        assert_eq!(parse_duration("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("ten").is_err());
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("99999999999999999999999s").is_err());
+    }
+
+    #[test]
+    fn cli_commands() {
+        assert_eq!(parse(&args("3")).unwrap().command, Command::Run);
+        assert_eq!(parse(&args("run 3")).unwrap().command, Command::Run);
+        let cli = parse(&args("run 2023 1-3")).unwrap();
+        assert_eq!((cli.year, cli.days), (2023, vec![1, 2, 3]));
+        let cli = parse(&args("7")).unwrap();
+        assert_eq!((cli.year, cli.days), (aoc::latest_year(), vec![7]));
+        assert!(parse(&args("2015 all")).is_err());
+        assert!(parse(&args("2024")).is_err());
+        assert!(parse(&args("2014 1")).is_err());
+        assert!(parse(&args("2024 2023 1")).is_err());
+        let cli = parse(&args("verify all --save")).unwrap();
+        assert_eq!(cli.command, Command::Verify);
+        assert_eq!(parse(&args("check all")).unwrap().command, Command::Check);
+        assert!(cli.save);
+        let cli = parse(&args("bench 6 --iterations 20 --budget 1s")).unwrap();
+        assert_eq!(cli.command, Command::Bench);
+        assert_eq!(cli.bench.iterations, 20);
+        assert_eq!(cli.bench.budget, Duration::from_secs(1));
+        let cli = parse(&args("fetch 10 --base-url http://localhost:8080")).unwrap();
+        assert_eq!(cli.command, Command::Fetch);
+        assert_eq!(cli.days, vec![10]);
+        assert_eq!(cli.base_url.as_deref(), Some("http://localhost:8080"));
+        let cli = parse(&args("submit 7 2")).unwrap();
+        assert_eq!(cli.command, Command::Submit);
+        assert_eq!((cli.days, cli.part), (vec![7], Some(2)));
+        let cli = parse(&args("new 10 --title Hoof")).unwrap();
+        assert_eq!(cli.command, Command::New);
+        assert_eq!(cli.title.as_deref(), Some("Hoof"));
+        assert!(parse(&args("new 10-11")).is_err());
+        assert!(parse(&args("run 10 --title Hoof")).is_err());
+        let cli = parse(&args("watch 7 --example")).unwrap();
+        assert_eq!((cli.command, cli.input), (Command::Watch, Input::Example));
+        assert!(parse(&args("watch 7-8")).is_err());
+        assert!(parse(&args("watch 7 -")).is_err());
+        assert_eq!(
+            parse(&args("all --format csv")).unwrap().format,
+            Format::Csv
+        );
+        let cli = parse(&args("all --jobs 4 --timeout 10s")).unwrap();
+        assert_eq!(cli.run.jobs, 4);
+        assert_eq!(cli.run.timeout, Some(Duration::from_secs(10)));
+        assert!(parse(&args("7 --mem")).unwrap().run.memory);
+        assert!(parse(&args("bench 7 --mem")).is_err());
+        let cli = parse(&args("compare all --threshold 5%")).unwrap();
+        assert_eq!((cli.command, cli.threshold), (Command::Compare, Some(5.0)));
+        assert_eq!(parse(&args("6 --part 2")).unwrap().run.part, Some(2));
+        let cli = parse(&args("7 --variant string")).unwrap();
+        assert_eq!(cli.run.variant.as_deref(), Some("string"));
+        assert_eq!(
+            parse(&args("crosscheck all")).unwrap().command,
+            Command::Crosscheck
+        );
+        assert!(parse(&args("compare all --threshold -1")).is_err());
+        assert!(parse(&args("bench all --threshold 5")).is_err());
+        assert!(parse(&args("compare all --jobs 2")).is_err());
+    }
+}
@@ -0,0 +1,351 @@
+//! A small HTTP client for downloading inputs and submitting answers.
+
+use crate::{
+    aoc::Verdict,
+    error::{AocError, Result},
+};
+use regex::Regex;
+use std::{env, fs, path::Path, time::Duration};
+
+/// Default server, overridden by `AOC_BASE_URL` or `--base-url`.
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// File holding the session cookie when `AOC_SESSION` is not set.
+pub const SESSION_FILE: &str = "session.id";
+const USER_AGENT: &str = "aoc-2024 (https://github.com/vieitesss/aoc-2024)";
+
+/// An authenticated client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// A client for `base_url` using the `session` cookie.
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// A client for `base_url`, `AOC_BASE_URL` or [`BASE_URL`], authenticated with
+    /// `AOC_SESSION` or [`SESSION_FILE`].
+    pub fn from_env(base_url: Option<&str>) -> Result<Client> {
+        let base_url = match base_url {
+            Some(url) => url.to_string(),
+            None => env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(BASE_URL)),
+        };
+
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => fs::read_to_string(SESSION_FILE).map_err(|err| {
+                AocError::Http(format!(
+                    "No session: set AOC_SESSION or create {SESSION_FILE} ({err})"
+                ))
+            })?,
+        };
+
+        if session.trim().is_empty() {
+            return Err(AocError::Http(String::from("The session cookie is empty")));
+        }
+
+        Ok(Client::new(&base_url, &session))
+    }
+
+    fn url(&self, year: usize, day: usize, path: &str) -> String {
+        format!("{}/{year}/day/{day}{path}", self.base_url)
+    }
+
+    fn body(
+        day: usize,
+        response: std::result::Result<ureq::Response, ureq::Error>,
+    ) -> Result<String> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(404, _)) => {
+                Err(AocError::Http(format!("Day {day} is not unlocked yet")))
+            }
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                if body.contains("log in") {
+                    return Err(AocError::Http(String::from(
+                        "Not logged in: the session cookie is missing or expired",
+                    )));
+                }
+                Err(AocError::Http(format!("Unexpected status {code}")))
+            }
+            Err(err) => Err(AocError::Http(err.to_string())),
+        }
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, year: usize, day: usize) -> Result<String> {
+        let response = self
+            .agent
+            .get(&self.url(year, day, "/input"))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        let html = matches!(&response, Ok(response) if response.content_type() == "text/html");
+        let body = Client::body(day, response)?;
+
+        if body.trim().is_empty() {
+            return Err(AocError::Http(format!("Empty input for day {day}")));
+        }
+
+        if html || body.contains("log in") || is_html(&body) {
+            return Err(AocError::Http(String::from(
+                "Expected the puzzle input but got a web page, is the session cookie valid?",
+            )));
+        }
+
+        Ok(body)
+    }
+
+    /// Submits the answer of a part and reads the verdict from the response.
+    pub fn submit(&self, year: usize, day: usize, part: usize, answer: &str) -> Result<Verdict> {
+        let response = self
+            .agent
+            .post(&self.url(year, day, "/answer"))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let body = Client::body(day, response)?;
+
+        if body.contains("log in") {
+            return Err(AocError::Http(String::from(
+                "Not logged in: the session cookie is missing or expired",
+            )));
+        }
+
+        Ok(verdict(&body))
+    }
+
+    /// Downloads the input of a day to `path`. Returns false if it was already there.
+    pub fn fetch(&self, year: usize, day: usize, path: &Path) -> Result<bool> {
+        if path.exists() {
+            return Ok(false);
+        }
+
+        let input = self.input(year, day)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let partial = path.with_extension("partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, path)?;
+
+        Ok(true)
+    }
+}
+
+// Whether a body is a web page rather than an input, some of which start with '<'.
+fn is_html(body: &str) -> bool {
+    let start = body.trim_start().as_bytes();
+    ["<!doctype", "<html"].iter().any(|tag| {
+        start
+            .get(..tag.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(tag.as_bytes()))
+    })
+}
+
+/// Reads the verdict from the page returned after submitting.
+pub fn verdict(body: &str) -> Verdict {
+    if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("You gave an answer too recently") {
+        let regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        let wait = regex.captures(body).map_or(0, |c| {
+            let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+            let seconds: u64 = c[2].parse().unwrap_or(0);
+            minutes * 60 + seconds
+        });
+        Verdict::RateLimited(Duration::from_secs(wait))
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if body.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+        thread,
+    };
+
+    fn serve(status: &str, body: &str) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+
+            // The body of a POST can arrive after the headers.
+            loop {
+                let n = stream.read(&mut buffer).unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..n]);
+
+                let text = String::from_utf8_lossy(&request).to_lowercase();
+                let Some((headers, body)) = text.split_once("\r\n\r\n") else {
+                    continue;
+                };
+                let length = headers
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .and_then(|length| length.trim().parse().ok())
+                    .unwrap_or(0);
+                if body.len() >= length {
+                    break;
+                }
+            }
+
+            let _ = sender.send(String::from_utf8_lossy(&request).to_string());
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        (url, receiver)
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-2024-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("input").join("day3")
+    }
+
+    #[test]
+    fn fetch_caches_input() {
+        let (url, request) = serve("200 OK", "mul(2,4)\n");
+        let client = Client::new(&url, "secret\n");
+        let path = temp_path("fetch");
+
+        assert!(client.fetch(2024, 3, &path).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "mul(2,4)\n");
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/3/input HTTP/1.1"));
+        assert!(request.contains("session=secret\r\n"));
+
+        assert!(!client.fetch(2024, 3, &path).unwrap());
+    }
+
+    #[test]
+    fn fetch_not_unlocked() {
+        let (url, _) = serve(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint",
+        );
+        let client = Client::new(&url, "secret");
+        let path = temp_path("locked");
+
+        let err = client.fetch(2024, 3, &path).unwrap_err();
+        assert!(err.to_string().contains("not unlocked"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn fetch_not_logged_in() {
+        let (url, _) = serve(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let client = Client::new(&url, "expired");
+
+        let err = client.input(2024, 3).unwrap_err();
+        assert!(err.to_string().contains("Not logged in"));
+    }
+
+    #[test]
+    fn submit_posts_answer() {
+        let (url, request) = serve(
+            "200 OK",
+            "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+        );
+        let client = Client::new(&url, "secret");
+
+        assert_eq!(client.submit(2024, 3, 2, "48").unwrap(), Verdict::Correct);
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/3/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=48"));
+    }
+
+    #[test]
+    fn submit_verdicts() {
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict("That's not the right answer. If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently. You have 1m 23s left to wait."),
+            Verdict::RateLimited(Duration::from_secs(83))
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently. You have 42s left to wait."),
+            Verdict::RateLimited(Duration::from_secs(42))
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert_eq!(verdict("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn fetch_input_starting_with_angle_bracket() {
+        let (url, _) = serve("200 OK", "<<>^v>\n");
+        let client = Client::new(&url, "secret");
+        let path = temp_path("angle");
+
+        assert!(client.fetch(2015, 3, &path).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "<<>^v>\n");
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn fetch_rejects_html() {
+        assert!(is_html("\n<HTML><body></body></html>"));
+        assert!(!is_html("<<>^v>"));
+
+        let (url, _) = serve("200 OK", "<!DOCTYPE html><html></html>");
+        let client = Client::new(&url, "secret");
+        let path = temp_path("html");
+
+        assert!(client.fetch(2024, 3, &path).is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,110 @@
+//! The error type shared by solutions and the runner.
+
+use std::{error, fmt, io, time::Duration};
+
+/// Result of parsing and solving.
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Why a day could not be parsed or solved.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A line of the input could not be parsed.
+    Parse {
+        /// Day being parsed, 0 until [`AocError::in_day`] fills it in.
+        day: usize,
+        /// 1-based line number.
+        line: usize,
+        /// 1-based column of the offending text.
+        column: usize,
+        /// The offending text.
+        text: String,
+        /// What was expected.
+        reason: String,
+    },
+    /// The input is well-formed but can't be solved as given.
+    InvalidInput(String),
+    /// The solution gave up, e.g. an endless loop was detected.
+    Unsolvable(String),
+    /// The part didn't finish within the timeout.
+    Timeout(Duration),
+    /// The solution panicked, so the worker running the day stopped.
+    Panicked,
+    /// Talking to the website failed.
+    Http(String),
+    /// An answer was not submitted, e.g. the history already rules it out.
+    NotSubmitted(String),
+}
+
+impl AocError {
+    /// A parse error at `line` and `column`, for any day.
+    pub fn parse(line: usize, column: usize, text: &str, reason: &str) -> AocError {
+        AocError::Parse {
+            day: 0,
+            line,
+            column,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Fills in the day of a parse error.
+    pub fn in_day(self, number: usize) -> AocError {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                text,
+                reason,
+                ..
+            } => AocError::Parse {
+                day: number,
+                line,
+                column,
+                text,
+                reason,
+            },
+            err => err,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(err) => write!(f, "I/O error: {err}"),
+            AocError::Parse {
+                day,
+                line,
+                column,
+                text,
+                reason,
+            } => write!(
+                f,
+                "day {day}, line {line}, column {column}: {reason}: '{text}'"
+            ),
+            AocError::InvalidInput(reason) => write!(f, "invalid input: {reason}"),
+            AocError::Unsolvable(reason) => write!(f, "unsolvable: {reason}"),
+            AocError::Timeout(timeout) => write!(f, "TIMEOUT after {timeout:?}"),
+            AocError::Panicked => write!(f, "the solution panicked"),
+            AocError::Http(reason) => write!(f, "HTTP error: {reason}"),
+            AocError::NotSubmitted(reason) => write!(f, "not submitted: {reason}"),
+        }
+    }
+}
+
+impl error::Error for AocError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AocError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> Self {
+        AocError::Io(err)
+    }
+}
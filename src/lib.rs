@@ -0,0 +1,23 @@
+//! Advent of Code 2024 solutions and the runner behind the `aoc-2024` binary.
+//!
+//! [`aoc`] holds the [`aoc::Solution`] trait, the registry of years and days and
+//! the runner, and [`utils`] the grid and parsing helpers shared by every year.
+//!
+//! ```no_run
+//! use aoc_2024::aoc::{self, Input, RunOptions};
+//!
+//! let day = aoc::find_day(2024, 7).unwrap();
+//! let report = aoc::run_day(day, &Input::Example, &RunOptions::default());
+//! println!("{:?}", report.part(1).map(|p| &p.answer));
+//! ```
+
+#![warn(missing_docs)]
+
+pub mod aoc;
+pub mod client;
+pub mod error;
+pub mod utils;
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: aoc::Counting = aoc::Counting;
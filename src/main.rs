@@ -1,48 +1,249 @@
-mod aoc;
-mod utils;
+mod cli;
+mod scaffold;
+mod watch;
 
-use aoc::{
-    day1::Day1, day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8,
-    day9::Day9, Solution,
+use aoc_2024::aoc::{self, Baselines, Bench, Day, Input, Verdict};
+use aoc_2024::client::Client;
+use cli::{Args, Command};
+use std::{
+    env,
+    path::Path,
+    process::{exit, ExitCode},
+    time::Instant,
 };
-use std::{env, io, process::exit};
 
-fn usage_exit(day: &str) {
-    eprintln!("Usage: {} <day>", day);
-    eprintln!("\n\t- day: number between 1 and 25");
+#[global_allocator]
+static ALLOCATOR: aoc::Counting = aoc::Counting;
+
+fn usage_exit(program: &str, err: &str) -> ! {
+    eprintln!("{err}\n");
+    eprintln!("{}", cli::USAGE.replace("{program}", program));
     exit(1);
 }
 
-fn main() -> Result<(), io::Error> {
-    let args: Vec<String> = env::args().collect();
+fn find_days(cli: &Args) -> (Vec<&'static Day>, bool) {
+    let mut found = true;
+    let mut days = Vec::new();
+
+    for &n in &cli.days {
+        match aoc::find_day(cli.year, n) {
+            Some(day) => days.push(day),
+            None => {
+                eprintln!("{} day {n} not implemented yet", cli.year);
+                found = false;
+            }
+        }
+    }
+
+    (days, found)
+}
+
+fn run_benches(cli: &Args) -> (Vec<Bench>, bool) {
+    let (days, found) = find_days(cli);
+    let (benches, passed) = aoc::bench_days(&days, &cli.input, &cli.bench);
+
+    (benches, found && passed)
+}
+
+fn bench(cli: &Args) -> bool {
+    let (benches, mut passed) = run_benches(cli);
+    aoc::print_benches(&benches, cli.format);
+
+    let commit = aoc::commit();
+    let recorded = Baselines::load(cli.year).and_then(|mut baselines| {
+        benches
+            .iter()
+            .try_for_each(|bench| baselines.record(bench, commit.as_deref()))
+    });
+    if let Err(err) = recorded {
+        eprintln!("Could not record the bench history: {err}");
+        passed = false;
+    }
+
+    passed
+}
+
+fn compare(cli: &Args) -> bool {
+    let baselines = match Baselines::load(cli.year) {
+        Ok(baselines) => baselines,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    let threshold = cli.threshold.unwrap_or(aoc::DEFAULT_THRESHOLD);
+    let (benches, mut passed) = run_benches(cli);
+
+    for bench in &benches {
+        let comparisons = baselines.compare(bench, threshold);
+        aoc::print_comparison(bench, &comparisons, threshold);
+        passed &= !comparisons.iter().any(|c| c.regression);
+    }
+
+    passed
+}
+
+fn check(cli: &Args) -> bool {
+    let mut passed = true;
+
+    for &n in &cli.days {
+        let Some(day) = aoc::find_day(cli.year, n) else {
+            eprintln!("{} day {n} not implemented yet", cli.year);
+            passed = false;
+            continue;
+        };
+
+        println!("Day {n}: {} ({})", day.title, cli.input.source(cli.year, n));
+        match aoc::check_input(day, &cli.input) {
+            Ok(problems) if problems.is_empty() => println!("OK"),
+            Ok(problems) => {
+                problems.iter().for_each(|problem| println!("{problem}"));
+                passed = false;
+            }
+            Err(err) => {
+                println!("{err}");
+                passed = false;
+            }
+        }
+    }
+
+    passed
+}
+
+fn crosscheck(cli: &Args) -> bool {
+    let mut passed = true;
 
-    if args.len() < 2 {
-        usage_exit(&args[0]);
+    for &n in &cli.days {
+        let Some(day) = aoc::find_day(cli.year, n) else {
+            eprintln!("{} day {n} not implemented yet", cli.year);
+            passed = false;
+            continue;
+        };
+
+        match aoc::crosscheck(day, &cli.input) {
+            Ok(crosscheck) => passed &= aoc::print_crosscheck(&crosscheck),
+            Err(err) => {
+                eprintln!("day {n}: {err}");
+                passed = false;
+            }
+        }
     }
 
-    let day: usize = args[1].parse().unwrap();
+    passed
+}
+
+fn fetch(cli: &Args) -> bool {
+    let client = match Client::from_env(cli.base_url.as_deref()) {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    let mut passed = true;
+
+    for &day in &cli.days {
+        let Some(path) = Input::Puzzle.path(cli.year, day) else {
+            continue;
+        };
 
-    if day < 1 || day > 25 {
-        usage_exit(&args[0]);
+        match client.fetch(cli.year, day, &path) {
+            Ok(true) => println!("Downloaded day {day} to {}", path.display()),
+            Ok(false) => println!("Day {day} already at {}", path.display()),
+            Err(err) => {
+                eprintln!("day {day}: {err}");
+                passed = false;
+            }
+        }
     }
 
-    let mut day1 = Day1::default();
-    let mut day2 = Day2::default();
-    let mut day3 = Day3::default();
-    let mut day4 = Day4::default();
-    let mut day5 = Day5::default();
-    let mut day6 = Day6::default();
-    let mut day7 = Day7::default();
-    let mut day8 = Day8::default();
-    let mut day9 = Day9::default();
+    passed
+}
+
+fn new_day(cli: &Args) -> bool {
+    let day = cli.days[0];
+    let title = cli.title.clone().unwrap_or_else(|| format!("Day {day}"));
 
-    let mut days: Vec<&mut dyn Solution> = vec![
-        &mut day1, &mut day2, &mut day3, &mut day4, &mut day5, &mut day6, &mut day7, &mut day8,
-        &mut day9,
-    ];
+    match scaffold::new_day(Path::new("."), cli.year, day, &title) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            true
+        }
+        Err(err) => {
+            eprintln!("day {day}: {err}");
+            false
+        }
+    }
+}
 
-    let d = &mut days[day - 1];
-    aoc::run_day(*d);
+fn submit(cli: &Args) -> bool {
+    let (n, part) = (cli.days[0], cli.part.unwrap_or(1));
+    let Some(day) = aoc::find_day(cli.year, n) else {
+        eprintln!("{} day {n} not implemented yet", cli.year);
+        return false;
+    };
 
-    Ok(())
+    match aoc::submit(day, part, &cli.run, cli.base_url.as_deref()) {
+        Ok(verdict) => {
+            println!("{}", verdict.message());
+            verdict == Verdict::Correct
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        }
+    }
+}
+
+fn run(cli: &Args) -> bool {
+    let (days, mut passed) = find_days(cli);
+
+    let start = Instant::now();
+    let reports = aoc::run_days(&days, &cli.input, &cli.run);
+    let wall = start.elapsed();
+
+    passed &= reports.iter().all(|r| r.errors().is_empty());
+
+    if cli.command == Command::Verify {
+        for report in &reports {
+            match aoc::verify_day(report, cli.save) {
+                Ok(verified) => passed &= verified,
+                Err(err) => {
+                    eprintln!("day {}: {err}", report.day.number);
+                    passed = false;
+                }
+            }
+        }
+    } else {
+        aoc::print_reports(&reports, cli.format, wall, cli.run.jobs);
+    }
+
+    passed
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let cli = cli::parse(&args[1..]).unwrap_or_else(|err| usage_exit(&args[0], &err));
+
+    let passed = match cli.command {
+        Command::Bench => bench(&cli),
+        Command::Check => check(&cli),
+        Command::Compare => compare(&cli),
+        Command::Crosscheck => crosscheck(&cli),
+        Command::Fetch => fetch(&cli),
+        Command::New => new_day(&cli),
+        Command::Submit => submit(&cli),
+        Command::Watch => watch::watch(&cli),
+        Command::Run | Command::Verify => run(&cli),
+    };
+
+    if passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
 }
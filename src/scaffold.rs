@@ -0,0 +1,294 @@
+use aoc_2024::error::{AocError, Result};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.tpl");
+
+// Inserts `entry` into the `block! { ... }` registry, keeping the keys before `=>` in order.
+fn register(registry: &str, block: &str, key: usize, entry: &str) -> Result<String> {
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(&format!("{block}! {{")))
+        .ok_or_else(|| AocError::InvalidInput(format!("No {block}! registry")))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "}")
+            .ok_or_else(|| AocError::InvalidInput(format!("Unterminated {block}! registry")))?;
+
+    let mut at = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let number: usize = line
+            .split("=>")
+            .next()
+            .and_then(|n| n.trim().parse().ok())
+            .unwrap_or(0);
+
+        if number == key {
+            return Err(AocError::InvalidInput(format!(
+                "{key} is already registered in {block}!"
+            )));
+        }
+        if number > key {
+            at = i;
+            break;
+        }
+    }
+
+    lines.insert(at, format!("    {entry}"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Declares `pub mod <module>;` with its doc among the `pub mod <prefix>...;` declarations
+// before the `block! { ... }` registry, keeping them in order.
+fn declare(source: &str, block: &str, module: &str, doc: &str) -> Result<String> {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let registry = lines
+        .iter()
+        .position(|line| line.starts_with(&format!("{block}! {{")))
+        .ok_or_else(|| AocError::InvalidInput(format!("No {block}! registry")))?;
+    let declaration = format!("pub mod {module};");
+    let prefix: String = module.chars().take_while(|c| !c.is_ascii_digit()).collect();
+    let declared: Vec<usize> = (0..registry)
+        .filter(|&i| lines[i].starts_with(&format!("pub mod {prefix}")))
+        .collect();
+
+    if lines[..registry].contains(&declaration) {
+        return Err(AocError::InvalidInput(format!(
+            "{module} is already declared"
+        )));
+    }
+
+    let at = match declared.iter().find(|&&i| lines[i] > declaration) {
+        Some(&i) if i > 0 && lines[i - 1].starts_with("///") => i - 1,
+        Some(&i) => i,
+        None => match declared.last() {
+            Some(&i) => i + 1,
+            None => {
+                lines.insert(registry, String::new());
+                registry
+            }
+        },
+    };
+
+    lines.insert(at, declaration);
+    lines.insert(at, format!("/// {doc}"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_day(registry: &str, day: usize, title: &str) -> Result<String> {
+    let declared = declare(
+        registry,
+        "days",
+        &format!("day{day:02}"),
+        &format!("Day {day}: {title}"),
+    )?;
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    let entry = format!("{day} => day{day:02}::Day{day}, \"{title}\";");
+
+    register(&declared, "days", day, &entry)
+}
+
+fn register_year(registry: &str, year: usize) -> Result<String> {
+    let declared = declare(
+        registry,
+        "years",
+        &format!("year{year}"),
+        &format!("Advent of Code {year}"),
+    )?;
+
+    register(&declared, "years", year, &format!("{year} => year{year};"))
+}
+
+const MANIFEST: &str = "\
+# Expected answers of the examples in this directory, a [dayNN] or
+# [dayNN-name] section per file. Each one becomes a test.
+";
+
+// Appends a `[dayNN]` section with placeholder answers to an example manifest.
+fn add_example(manifest: &str, day: usize) -> Result<String> {
+    let section = format!("[day{day:02}]");
+
+    if manifest.lines().any(|line| line.trim() == section) {
+        return Err(AocError::InvalidInput(format!(
+            "{section} is already in the manifest"
+        )));
+    }
+
+    Ok(format!(
+        "{}\n\n{section}\n# part1 = \"\"\n# part2 = \"\"\n",
+        manifest.trim_end()
+    ))
+}
+
+pub fn new_day(root: &Path, year: usize, day: usize, title: &str) -> Result<Vec<PathBuf>> {
+    let source = root.join(format!("src/aoc/year{year}/day{day:02}.rs"));
+    let example = root.join(format!("example/{year}/day{day:02}"));
+    let registry = root.join(format!("src/aoc/year{year}.rs"));
+    let years = root.join("src/aoc.rs");
+    let manifest = root.join(format!("example/{year}/manifest"));
+
+    for path in [&source, &example] {
+        if path.exists() {
+            return Err(AocError::InvalidInput(format!(
+                "{} already exists",
+                path.display()
+            )));
+        }
+    }
+
+    let mut written = Vec::new();
+    let new_year = !registry.exists();
+    let days = if new_year {
+        format!("days! {{\n    year: {year};\n}}\n")
+    } else {
+        fs::read_to_string(&registry)?
+    };
+    let days = register_day(&days, day, title)?;
+    let years_registered = if new_year {
+        Some(register_year(&fs::read_to_string(&years)?, year)?)
+    } else {
+        None
+    };
+
+    let examples = match fs::read_to_string(&manifest) {
+        Ok(data) => add_example(&data, day)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => add_example(MANIFEST, day)?,
+        Err(err) => return Err(err.into()),
+    };
+
+    let template = TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{NN}}", &format!("{day:02}"))
+        .replace("{{N}}", &day.to_string());
+
+    for path in [&source, &example] {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    fs::write(&source, template)?;
+    written.push(source);
+    fs::write(&example, "")?;
+    written.push(example);
+    fs::write(&manifest, examples)?;
+    written.push(manifest);
+    fs::write(&registry, days)?;
+    written.push(registry);
+    if let Some(years_registered) = years_registered {
+        fs::write(&years, years_registered)?;
+        written.push(years);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    const YEARS: &str = "\
+/// Advent of Code 2024
+pub mod year2024;
+
+years! {
+    2024 => year2024;
+}
+";
+
+    const DAYS: &str = "\
+/// Day 1: Historian Hysteria
+pub mod day01;
+/// Day 9: Disk Fragmenter
+pub mod day09;
+
+days! {
+    year: 2024;
+    1 => day01::Day1, \"Historian Hysteria\";
+    9 => day09::Day9, \"Disk Fragmenter\";
+}
+";
+
+    #[test]
+    fn scaffold_register_in_order() {
+        let registry = register_day(DAYS, 5, "Print \"Queue\"").unwrap();
+        let lines: Vec<&str> = registry.lines().collect();
+        assert_eq!(
+            lines[2..4],
+            ["/// Day 5: Print \"Queue\"", "pub mod day05;"]
+        );
+        assert_eq!(lines[10], "    5 => day05::Day5, \"Print \\\"Queue\\\"\";");
+
+        let registry = register_day(DAYS, 10, "Day 10").unwrap();
+        let lines: Vec<&str> = registry.lines().collect();
+        assert_eq!(lines[4..6], ["/// Day 10: Day 10", "pub mod day10;"]);
+        assert_eq!(lines[11], "    10 => day10::Day10, \"Day 10\";");
+
+        assert!(register_day(DAYS, 9, "Again").is_err());
+        assert!(register_day("fn main() {}", 9, "Again").is_err());
+
+        let registry = register_year(YEARS, 2023).unwrap();
+        let lines: Vec<&str> = registry.lines().collect();
+        assert_eq!(lines[..2], ["/// Advent of Code 2023", "pub mod year2023;"]);
+        assert_eq!(lines[6], "    2023 => year2023;");
+        assert!(register_year(YEARS, 2024).is_err());
+    }
+
+    #[test]
+    fn scaffold_new_day() {
+        let root = env::temp_dir().join(format!("aoc-2024-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/aoc")).unwrap();
+        fs::write(root.join("src/aoc.rs"), YEARS).unwrap();
+        fs::write(root.join("src/aoc/year2024.rs"), DAYS).unwrap();
+
+        new_day(&root, 2024, 10, "Hoof It").unwrap();
+
+        let source = fs::read_to_string(root.join("src/aoc/year2024/day10.rs")).unwrap();
+        assert!(source.contains("pub struct Day10;"));
+        assert!(source.contains("\"./example/2024/day10\""));
+        assert_eq!(
+            fs::read_to_string(root.join("example/2024/day10")).unwrap(),
+            ""
+        );
+        assert!(fs::read_to_string(root.join("src/aoc/year2024.rs"))
+            .unwrap()
+            .contains("pub mod day09;\n/// Day 10: Hoof It\npub mod day10;\n"));
+        assert!(fs::read_to_string(root.join("src/aoc/year2024.rs"))
+            .unwrap()
+            .contains("10 => day10::Day10, \"Hoof It\";"));
+        assert_eq!(
+            fs::read_to_string(root.join("example/2024/manifest")).unwrap(),
+            format!("{MANIFEST}\n[day10]\n# part1 = \"\"\n# part2 = \"\"\n")
+        );
+        assert!(new_day(&root, 2024, 10, "Hoof It").is_err());
+
+        fs::write(
+            root.join("example/2024/manifest"),
+            "[day11]\npart1 = \"1\"\n",
+        )
+        .unwrap();
+        assert!(new_day(&root, 2024, 11, "Day 11").is_err());
+        assert!(!root.join("src/aoc/year2024/day11.rs").exists());
+
+        new_day(&root, 2023, 1, "Not Quite Lisp").unwrap();
+        assert!(fs::read_to_string(root.join("src/aoc/year2023.rs"))
+            .unwrap()
+            .starts_with(
+                "/// Day 1: Not Quite Lisp\npub mod day01;\n\ndays! {\n    year: 2023;\n    1 => day01::Day1, \"Not Quite Lisp\";"
+            ));
+        assert!(fs::read_to_string(root.join("src/aoc.rs"))
+            .unwrap()
+            .contains("pub mod year2023;\n/// Advent of Code 2024\npub mod year2024;\n\nyears! {\n    2023 => year2023;\n    2024 => year2024;\n}\n"));
+        assert!(root.join("example/2023/day01").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
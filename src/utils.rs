@@ -1,3 +1,14 @@
+//! Grid and parsing helpers shared by the days.
+
+/// The eight grid directions.
 pub mod dir;
-pub mod parser;
+/// Byte grids viewed in place over the input.
+pub mod grid;
+/// Hashing of inputs.
+pub mod hash;
+/// Grids as vectors of rows.
 pub mod matrix;
+/// Input parsing with error positions.
+pub mod parser;
+/// Property-based testing with a seeded generator and shrinking.
+pub mod prop;
@@ -1,16 +1,26 @@
+/// A direction on a grid, with rows growing downwards.
 #[derive(Default, Debug, PartialEq, Eq, Copy, Clone)]
 pub enum Dir {
+    /// One row up, one column left.
     TopLeft,
+    /// One row up.
     #[default]
     Top,
+    /// One row up, one column right.
     TopRight,
+    /// One column right.
     Right,
+    /// One row down, one column right.
     BottomRight,
+    /// One row down.
     Bottom,
+    /// One row down, one column left.
     BottomLeft,
+    /// One column left.
     Left,
 }
 
+/// All directions, clockwise from [`Dir::TopLeft`].
 pub const DIRS: [Dir; 8] = [
     Dir::TopLeft,
     Dir::Top,
@@ -23,6 +33,7 @@ pub const DIRS: [Dir; 8] = [
 ];
 
 impl Dir {
+    /// The `(row, column)` offset of the direction.
     pub fn values(&self) -> (isize, isize) {
         match *self {
             Dir::TopLeft => (-1, -1),
@@ -36,8 +47,9 @@ impl Dir {
         }
     }
 
+    /// The position one step from `pos` in this direction.
     pub fn from(&self, pos: (isize, isize)) -> (isize, isize) {
         let vals = self.values();
-        (pos.0 as isize + vals.0, pos.1 as isize + vals.1)
+        (pos.0 + vals.0, pos.1 + vals.1)
     }
 }
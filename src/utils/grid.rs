@@ -0,0 +1,130 @@
+use super::matrix::Matrix;
+use std::ops::Index;
+
+/// A rectangular grid viewed in place over the input bytes, a row per line.
+///
+/// Rows are found by stride, so every line must be as wide as the first one.
+/// `\n` and `\r\n` line endings and trailing whitespace are accepted.
+#[derive(Debug, Clone, Copy)]
+pub struct Grid<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<'a> Grid<'a> {
+    /// A view of `data` without copying it.
+    pub fn new(data: &'a str) -> Grid<'a> {
+        let bytes = data.trim_end().as_bytes();
+        let line = bytes
+            .iter()
+            .position(|&b| b == b'\n')
+            .unwrap_or(bytes.len());
+        let width = line - usize::from(line > 0 && bytes[line - 1] == b'\r');
+        let stride = line + 1;
+        let height = match bytes.len() {
+            0 => 0,
+            len => (len + stride - width) / stride,
+        };
+
+        Grid {
+            bytes,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The bytes of row `i`, without its line ending.
+    pub fn row(&self, i: usize) -> &'a [u8] {
+        let start = i * self.stride;
+        &self.bytes[start..start + self.width]
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        let grid = *self;
+        (0..self.height).map(move |i| grid.row(i))
+    }
+
+    /// Whether `(row, column)` is outside the grid.
+    pub fn is_outbounds(&self, pos: (isize, isize)) -> bool {
+        pos.0 < 0 || pos.1 < 0 || pos.0 as usize >= self.height || pos.1 as usize >= self.width
+    }
+
+    /// The byte at `(row, column)`, `None` outside the grid.
+    pub fn get(&self, pos: (isize, isize)) -> Option<u8> {
+        if self.is_outbounds(pos) {
+            return None;
+        }
+
+        Some(self[(pos.0 as usize, pos.1 as usize)])
+    }
+
+    /// Every `(row, column)` holding `byte`, row by row.
+    pub fn positions(&self, byte: u8) -> Vec<(usize, usize)> {
+        self.bytes
+            .iter()
+            .enumerate()
+            .filter(|&(i, &b)| b == byte && i % self.stride < self.width)
+            .map(|(i, _)| (i / self.stride, i % self.stride))
+            .collect()
+    }
+
+    /// An owned copy of the grid, for days that need to modify it.
+    pub fn to_matrix(&self) -> Matrix<u8> {
+        self.rows().map(|row| row.to_vec()).collect()
+    }
+}
+
+impl Index<(usize, usize)> for Grid<'_> {
+    type Output = u8;
+
+    fn index(&self, (i, j): (usize, usize)) -> &u8 {
+        &self.row(i)[j]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grid_views_rows_in_place() {
+        let data = "ab.\n.#c\n";
+        let grid = Grid::new(data);
+
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.row(1), b".#c");
+        assert_eq!(grid[(0, 1)], b'b');
+        assert_eq!(grid.get((1, 2)), Some(b'c'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.positions(b'.'), vec![(0, 2), (1, 0)]);
+        assert_eq!(grid.to_matrix(), vec![b"ab.".to_vec(), b".#c".to_vec()]);
+        assert_eq!(grid.row(0).as_ptr(), data.as_ptr());
+    }
+
+    #[test]
+    fn grid_line_endings() {
+        for data in ["ab\r\ncd\r\n", "ab\ncd", "ab\ncd\n\n"] {
+            let grid = Grid::new(data);
+            assert_eq!(grid.rows().collect::<Vec<_>>(), [b"ab", b"cd"], "{data:?}");
+            assert!(grid.positions(b'\r').is_empty());
+        }
+
+        assert_eq!(Grid::new("").height(), 0);
+        assert_eq!(Grid::new("abc").rows().count(), 1);
+    }
+}
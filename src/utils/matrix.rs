@@ -1,9 +1,16 @@
+/// A row of a [`Matrix`].
 pub type Row<T> = Vec<T>;
+/// A grid indexed by `[row][column]`.
 pub type Matrix<T> = Vec<Row<T>>;
 
+/// Bounds-checked access to a [`Matrix`] with signed positions.
 pub trait MatrixTrait<T> {
+    /// Whether `(row, column)` is outside the grid.
     fn is_outbounds(&self, pos: (isize, isize)) -> bool;
+    /// The element at `(row, column)`, `None` outside the grid.
+    #[allow(clippy::wrong_self_convention)]
     fn from_pos(&self, pos: (isize, isize)) -> Option<&T>;
+    /// Every `(row, column)` holding `element`, row by row.
     fn positions(&self, element: &T) -> Vec<(usize, usize)>
     where
         T: PartialEq;
@@ -14,7 +21,7 @@ impl<T> MatrixTrait<T> for Matrix<T> {
         pos.0 < 0
             || pos.1 < 0
             || pos.0 as usize >= self.len()
-            || self.get(0).map_or(true, |row| pos.1 as usize >= row.len())
+            || self.first().is_none_or(|row| pos.1 as usize >= row.len())
     }
 
     fn from_pos(&self, pos: (isize, isize)) -> Option<&T> {
@@ -1,8 +1,88 @@
-use super::matrix::Matrix;
+use crate::error::{AocError, Result};
+use std::str::FromStr;
 
-pub fn to_chars_matrix(data: &str) -> Matrix<char> {
-    data.to_string()
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
+/// 1-based column of `token` within `line`, which it must be a slice of.
+pub fn column(line: &str, token: &str) -> usize {
+    (token.as_ptr() as usize)
+        .checked_sub(line.as_ptr() as usize)
+        .and_then(|offset| line.get(..offset))
+        .map_or(1, |prefix| prefix.chars().count() + 1)
+}
+
+/// Parses `token`, a slice of `line`, as a number, reporting its position on error.
+pub fn number<T: FromStr>(line_number: usize, line: &str, token: &str) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| AocError::parse(line_number, column(line, token), token, "invalid number"))
+}
+
+/// Problems with a grid: no rows, rows of a different width than the first and
+/// characters that are not `allowed`.
+pub fn grid_problems(data: &str, allowed: impl Fn(char) -> bool) -> Vec<AocError> {
+    let mut problems = Vec::new();
+    let width = data.lines().next().map_or(0, |row| row.chars().count());
+
+    if width == 0 {
+        problems.push(AocError::InvalidInput(String::from("Empty grid")));
+        return problems;
+    }
+
+    for (i, line) in data.lines().enumerate() {
+        let len = line.chars().count();
+        if len != width {
+            problems.push(AocError::parse(
+                i + 1,
+                len.min(width) + 1,
+                line,
+                &format!("Expected {width} columns, found {len}"),
+            ));
+        }
+
+        for (j, c) in line.chars().enumerate() {
+            if !allowed(c) {
+                problems.push(AocError::parse(
+                    i + 1,
+                    j + 1,
+                    &c.to_string(),
+                    "Unexpected character",
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parser_grid_problems() {
+        let allowed = |c| c == '.' || c == '#';
+        assert!(grid_problems("..#\n#..\n", allowed).is_empty());
+        assert_eq!(grid_problems("", allowed).len(), 1);
+
+        let positions: Vec<(usize, usize)> = grid_problems("..#\n#.\n.x.\n", allowed)
+            .iter()
+            .map(|err| match err {
+                AocError::Parse { line, column, .. } => (*line, *column),
+                _ => panic!("Expected a parse error"),
+            })
+            .collect();
+        assert_eq!(positions, [(2, 3), (3, 2)]);
+    }
+
+    #[test]
+    fn parser_number_error_position() {
+        let line = "12 x4 7";
+        let token = line.split_whitespace().nth(1).unwrap();
+        match number::<u64>(3, line, token) {
+            Err(AocError::Parse {
+                line, column, text, ..
+            }) => assert_eq!((line, column, text.as_str()), (3, 4, "x4")),
+            _ => panic!("Expected a parse error"),
+        }
+        assert_eq!(number::<u64>(1, line, "12").unwrap(), 12);
+    }
 }
@@ -0,0 +1,253 @@
+use std::env;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Seed used when `AOC_PROP_SEED` is not set, so failures reproduce across runs.
+pub const DEFAULT_SEED: u64 = 0x2024;
+
+/// Cases generated when `AOC_PROP_CASES` is not set.
+pub const DEFAULT_CASES: usize = 200;
+
+const MAX_SHRINKS: usize = 1000;
+
+/// SplitMix64, a small and fast seeded generator.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// A generator that always yields the same sequence for `seed`.
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    /// The next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number between `low` and `high`, both included.
+    pub fn range(&mut self, low: usize, high: usize) -> usize {
+        assert!(low <= high);
+        low + (self.next_u64() % (high - low + 1) as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) / ((1u64 << 53) as f64) < p
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i));
+        }
+    }
+}
+
+/// Values that can be made smaller to find a minimal counterexample.
+pub trait Shrink: Clone {
+    /// Smaller candidates, the most aggressive first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+impl Shrink for usize {
+    fn shrink(&self) -> Vec<usize> {
+        let mut candidates = vec![0, self / 2, self.saturating_sub(1)];
+        candidates.dedup();
+        candidates.retain(|c| c < self);
+        candidates
+    }
+}
+
+impl Shrink for i64 {
+    fn shrink(&self) -> Vec<i64> {
+        let mut candidates = vec![0, self / 2, self - self.signum()];
+        candidates.dedup();
+        candidates.retain(|c| c.abs() < self.abs());
+        candidates
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Vec<T>> {
+        let mut candidates = Vec::new();
+
+        let mut size = self.len() / 2;
+        while size > 0 {
+            for start in (0..=self.len() - size).step_by(size) {
+                let mut smaller = self[..start].to_vec();
+                smaller.extend_from_slice(&self[start + size..]);
+                candidates.push(smaller);
+            }
+            size /= 2;
+        }
+        if self.len() == 1 {
+            candidates.push(Vec::new());
+        }
+
+        for (i, item) in self.iter().enumerate() {
+            for smaller in item.shrink() {
+                let mut candidate = self.clone();
+                candidate[i] = smaller;
+                candidates.push(candidate);
+            }
+        }
+
+        candidates
+    }
+}
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<(A, B)> {
+        let first = self.0.shrink().into_iter().map(|a| (a, self.1.clone()));
+        let second = self.1.shrink().into_iter().map(|b| (self.0.clone(), b));
+        first.chain(second).collect()
+    }
+}
+
+/// A property that did not hold, already shrunk.
+#[derive(Debug)]
+pub struct Failure<T> {
+    /// Seed of the run, to reproduce it with `AOC_PROP_SEED`.
+    pub seed: u64,
+    /// Index of the first failing case.
+    pub case: usize,
+    /// The smallest failing value found.
+    pub value: T,
+    /// Why the smallest value failed.
+    pub reason: String,
+    /// Number of successful shrinks from the generated value.
+    pub shrinks: usize,
+}
+
+fn failure<T>(property: &impl Fn(&T) -> Result<(), String>, value: &T) -> Option<String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| property(value))) {
+        Ok(Ok(())) => None,
+        Ok(Err(reason)) => Some(reason),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Some(format!("panicked: {message}"))
+        }
+    }
+}
+
+/// Checks `property` on `cases` values from `generate`, shrinking the first failure.
+pub fn run<T: Shrink>(
+    seed: u64,
+    cases: usize,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Result<(), Failure<T>> {
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let mut value = generate(&mut rng);
+        let Some(mut reason) = failure(&property, &value) else {
+            continue;
+        };
+
+        let mut shrinks = 0;
+        'shrinking: while shrinks < MAX_SHRINKS {
+            for candidate in value.shrink() {
+                if let Some(why) = failure(&property, &candidate) {
+                    (value, reason) = (candidate, why);
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        return Err(Failure {
+            seed,
+            case,
+            value,
+            reason,
+            shrinks,
+        });
+    }
+
+    Ok(())
+}
+
+/// Like [`run`] with the seed and number of cases from `AOC_PROP_SEED` and
+/// `AOC_PROP_CASES`, panicking with the minimal counterexample.
+pub fn check<T: Shrink + Debug>(
+    name: &str,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    let seed = env::var("AOC_PROP_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+    let cases = env::var("AOC_PROP_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+
+    if let Err(failure) = run(seed, cases, generate, property) {
+        panic!(
+            "property '{name}' failed at case {} with AOC_PROP_SEED={}, shrunk {} times to\n{:?}\n{}",
+            failure.case, failure.seed, failure.shrinks, failure.value, failure.reason
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn prop_rng_is_seeded() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        assert_eq!(first, second);
+        assert_ne!(Rng::new(8).next_u64(), first[0]);
+        assert!((0..1000).all(|_| (3..=9).contains(&a.range(3, 9))));
+    }
+
+    #[test]
+    fn prop_shrinks_to_minimal_counterexample() {
+        let generate = |rng: &mut Rng| -> Vec<usize> {
+            let len = rng.range(0, 20);
+            (0..len).map(|_| rng.range(0, 100)).collect()
+        };
+        let property = |v: &Vec<usize>| {
+            if v.iter().any(|&n| n >= 50) {
+                Err(String::from("found a big number"))
+            } else {
+                Ok(())
+            }
+        };
+
+        let failure = run(1, 100, generate, property).unwrap_err();
+        assert_eq!(failure.value, vec![50]);
+        assert_eq!(failure.reason, "found a big number");
+    }
+
+    #[test]
+    fn prop_panics_are_failures() {
+        let failure = run(1, 10, |rng| rng.range(0, 10), |_| panic!("boom")).unwrap_err();
+        assert_eq!(
+            (failure.value, failure.reason.as_str()),
+            (0, "panicked: boom")
+        );
+    }
+
+    #[test]
+    fn prop_passing_property() {
+        assert!(run(1, 50, |rng| rng.range(0, 10), |_| Ok(())).is_ok());
+    }
+}
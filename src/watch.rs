@@ -0,0 +1,245 @@
+use crate::cli::Args;
+use aoc_2024::aoc::Input;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const POLL: Duration = Duration::from_millis(500);
+
+struct Watched {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watched {
+    fn new(paths: Vec<PathBuf>) -> Watched {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+
+        Watched { files }
+    }
+
+    fn changed(&mut self) -> Vec<&Path> {
+        let mut changed = Vec::new();
+
+        for (path, last) in &mut self.files {
+            let modified = modified(path);
+            if modified != *last {
+                *last = modified;
+                changed.push(path.as_path());
+            }
+        }
+
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+
+    fields
+}
+
+// Answers of parts 1 and 2 from `--format csv` output, errors prefixed with "error: ".
+fn answers(csv: &str) -> [Option<String>; 2] {
+    let mut answers = [None, None];
+
+    for line in csv.lines().skip(1) {
+        let fields = csv_fields(line);
+        let (Some(part), Some(answer), Some(error)) = (fields.get(2), fields.get(3), fields.get(8))
+        else {
+            continue;
+        };
+
+        let answer = match (answer.as_str(), error.as_str()) {
+            (_, "") if answer.is_empty() => String::from("-"),
+            (answer, "") => answer.to_string(),
+            (_, error) => format!("error: {error}"),
+        };
+
+        match part.as_str() {
+            "1" => answers[0] = Some(answer),
+            "2" => answers[1] = Some(answer),
+            _ => answers = [Some(format!("error: {}", error)), None],
+        }
+    }
+
+    answers
+}
+
+fn diff(previous: Option<&String>, current: Option<&String>) -> String {
+    let current = current.map_or("skipped", String::as_str);
+
+    match previous {
+        Some(previous) if previous == current => format!("{current} (unchanged)"),
+        Some(previous) => format!("{current} (was {previous})"),
+        None => current.to_string(),
+    }
+}
+
+fn build(release: bool) -> bool {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")));
+    cargo.args(["build", "--quiet"]);
+    if release {
+        cargo.arg("--release");
+    }
+
+    cargo.status().is_ok_and(|status| status.success())
+}
+
+fn run(exe: &Path, cli: &Args) -> Option<String> {
+    let mut child = Command::new(exe);
+    child
+        .args([cli.year.to_string(), cli.days[0].to_string()])
+        .args(["--format", "csv"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit());
+    if cli.input == Input::Example {
+        child.arg("--example");
+    }
+    if let Some(timeout) = cli.run.timeout {
+        child.args(["--timeout", &format!("{}ns", timeout.as_nanos())]);
+    }
+    if let Some(part) = cli.run.part {
+        child.args(["--part", &part.to_string()]);
+    }
+    if let Some(variant) = &cli.run.variant {
+        child.args(["--variant", variant]);
+    }
+
+    match child.output() {
+        Ok(output) => Some(String::from_utf8_lossy(&output.stdout).into_owned()),
+        Err(err) => {
+            eprintln!("Could not run {}: {err}", exe.display());
+            None
+        }
+    }
+}
+
+pub fn watch(cli: &Args) -> bool {
+    let (year, day) = (cli.year, cli.days[0]);
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(err) => {
+            eprintln!("Could not find the running binary: {err}");
+            return false;
+        }
+    };
+    let release = exe
+        .parent()
+        .is_some_and(|dir| dir.file_name().is_some_and(|name| name == "release"));
+
+    let mut watched = Watched::new(vec![
+        PathBuf::from(format!("./src/aoc/year{year}/day{day:02}.rs")),
+        PathBuf::from(format!("./input/{year}/day{day:02}")),
+        PathBuf::from(format!("./example/{year}/day{day:02}")),
+    ]);
+    let mut previous: [Option<String>; 2] = [None, None];
+    let mut changed = Vec::new();
+    let mut first = true;
+
+    println!(
+        "Watching {year} day {day} ({}), Ctrl-C to stop",
+        cli.input.source(year, day)
+    );
+
+    loop {
+        if first || !changed.is_empty() {
+            if !first {
+                println!("\n{} changed", changed.join(", "));
+            }
+            first = false;
+
+            if !build(release) {
+                println!("Build failed, waiting for changes");
+            } else if let Some(csv) = run(&exe, cli) {
+                let current = answers(&csv);
+                for part in 0..2 {
+                    println!(
+                        "Part {}: {}",
+                        part + 1,
+                        diff(previous[part].as_ref(), current[part].as_ref())
+                    );
+                }
+                previous = current;
+            }
+        }
+
+        thread::sleep(POLL);
+        changed = watched
+            .changed()
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn watch_detects_changes() {
+        let path = env::temp_dir().join(format!("aoc-2024-watch-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut watched = Watched::new(vec![path.clone()]);
+        assert!(watched.changed().is_empty());
+
+        fs::write(&path, "1").unwrap();
+        assert_eq!(watched.changed(), vec![path.as_path()]);
+        assert!(watched.changed().is_empty());
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watched.changed(), vec![path.as_path()]);
+    }
+
+    #[test]
+    fn watch_answers() {
+        let csv = "year,day,part,answer,parse_ns,time_ns,input,input_hash,error\n\
+                   2024,7,1,3749,10,20,./example/2024/day07,abc,\n\
+                   2024,7,2,,10,20,./example/2024/day07,abc,\"invalid input: a, b\"\n";
+        let parts = answers(csv);
+        assert_eq!(parts[0].as_deref(), Some("3749"));
+        assert_eq!(parts[1].as_deref(), Some("error: invalid input: a, b"));
+
+        let csv = "year,day,part,answer,parse_ns,time_ns,input,input_hash,error\n\
+                   2024,7,,,10,,./example/2024/day07,abc,bad line\n";
+        assert_eq!(answers(csv), [Some(String::from("error: bad line")), None]);
+    }
+
+    #[test]
+    fn watch_diff() {
+        let (old, new) = (String::from("41"), String::from("42"));
+        assert_eq!(diff(None, Some(&new)), "42");
+        assert_eq!(diff(Some(&old), Some(&new)), "42 (was 41)");
+        assert_eq!(diff(Some(&new), Some(&new)), "42 (unchanged)");
+        assert_eq!(diff(Some(&old), None), "skipped (was 41)");
+    }
+}
@@ -0,0 +1,41 @@
+use crate::aoc::{Answer, Solution};
+use crate::error::Result;
+
+/// Solution of [Day {{N}}](https://adventofcode.com/{{YEAR}}/day/{{N}}).
+pub struct Day{{N}};
+
+impl Solution for Day{{N}} {
+    type Parsed<'a> = ();
+
+    fn parse(_input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn part1(_input: &()) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(_input: &()) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn example() {
+        Day{{N}}::parse(&fs::read_to_string("./example/{{YEAR}}/day{{NN}}").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn day{{N}}_part1_example() {
+        assert_eq!(Day{{N}}::part1(&example()).unwrap(), Answer::Unsolved);
+    }
+
+    #[test]
+    fn day{{N}}_part2_example() {
+        assert_eq!(Day{{N}}::part2(&example()).unwrap(), Answer::Unsolved);
+    }
+}